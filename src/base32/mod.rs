@@ -3,20 +3,79 @@ extern crate regex;
 use self::regex::Regex;
 use super::{Validated, ValidatedWrapper};
 
+#[cfg(feature = "rocketly")]
+use super::{read_capped_string, Capped};
+
 use std::error::Error;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::ops::Deref;
 use std::str::FromStr;
 
 lazy_static! {
-    static ref BASE32_RE: Regex = {
-        Regex::new("^([A-Z2-7]{8})*(([A-Z2-7]{8})|([A-Z2-7]{7}=)|([A-Z2-7]{5}===)|([A-Z2-7]{4}====)|([A-Z2-7]{2}======))$").unwrap()
+    static ref BASE32_Z_BASE_32_RE: Regex = {
+        Regex::new("^[ybndrfg8ejkmcpqxot1uwisza345h769]*$").unwrap()
     };
 }
 
+/// The Crockford alphabet, excluding the ambiguous letters `I`, `L`, `O`, and `U`.
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The Crockford alphabet extended with its five check symbols, used to compute/verify the
+/// optional trailing check symbol (`value mod 37`).
+const CROCKFORD_CHECK_ALPHABET: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+/// Which base32 alphabet a `Base32Validator`/`Base32` validates against.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Base32Alphabet {
+    /// The RFC 4648 standard alphabet (`A-Z2-7`).
+    Standard,
+    /// The RFC 4648 "extended hex" alphabet (`0-9A-V`), as used by base32hex.
+    ExtendedHex,
+    /// Douglas Crockford's base32: case-insensitive, excludes the ambiguous letters `I L O U`,
+    /// reads `I`/`L` as `1` and `O` as `0`, allows `-` separators (stripped on normalization),
+    /// and may carry a trailing check symbol drawn from `* ~ $ = U` (the data's value mod 37).
+    Crockford,
+    /// z-base-32 (`ybndrfg8ejkmcpqxot1uwisza345h769`), a human-friendly alphabet ordered to
+    /// avoid visually-confusable characters. Case-insensitive; carries no padding.
+    ZBase32,
+}
+
+impl Default for Base32Alphabet {
+    #[inline]
+    fn default() -> Self {
+        Base32Alphabet::Standard
+    }
+}
+
+/// The padding policy used to validate a base32 string. Only meaningful for
+/// `Base32Alphabet::Standard` and `Base32Alphabet::ExtendedHex`; Crockford and z-base-32 never
+/// use `=` padding, so it's ignored for those alphabets.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PaddingPolicy {
+    /// Canonical `=` padding is mandatory (the historical, default behavior).
+    Required,
+    /// No `=` padding is allowed.
+    Forbidden,
+    /// `=` padding may be present or omitted.
+    Optional,
+}
+
+impl Default for PaddingPolicy {
+    #[inline]
+    fn default() -> Self {
+        PaddingPolicy::Required
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Base32Error {
     IncorrectFormat,
+    /// A byte outside the active alphabet (or an alphabet character following the start of `=`
+    /// padding) was found at this byte offset.
+    InvalidCharacter(usize),
+    TrailingBits,
+    CheckSymbolMismatch,
+    PaddingNotAllowed,
 }
 
 impl Display for Base32Error {
@@ -30,12 +89,16 @@ impl Error for Base32Error {}
 
 pub type Base32Result = Result<Base32, Base32Error>;
 
-#[derive(Debug, PartialEq)]
-pub struct Base32Validator {}
+#[derive(Debug, PartialEq, Default)]
+pub struct Base32Validator {
+    pub alphabet: Base32Alphabet,
+    pub padding: PaddingPolicy,
+}
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Base32 {
     base32: String,
+    alphabet: Base32Alphabet,
 }
 
 impl Base32 {
@@ -44,6 +107,11 @@ impl Base32 {
         &self.base32
     }
 
+    #[inline]
+    pub fn get_alphabet(&self) -> Base32Alphabet {
+        self.alphabet
+    }
+
     #[inline]
     pub fn into_string(self) -> String {
         self.base32
@@ -53,10 +121,121 @@ impl Base32 {
     pub unsafe fn from_string_unchecked(base32: String) -> Base32 {
         Base32 {
             base32,
+            alphabet: Base32Alphabet::Standard,
+        }
+    }
+
+    /// Decodes the validated base32 string into its raw bytes, assuming the RFC 4648 standard
+    /// alphabet.
+    pub fn decode(&self) -> Result<Vec<u8>, Base32Error> {
+        if self.alphabet != Base32Alphabet::Standard {
+            return Err(Base32Error::IncorrectFormat);
         }
+
+        decode_base32(&self.base32)
+    }
+
+    /// The compact representation used by non-human-readable serde formats: the decoded raw
+    /// bytes, which only `Base32Alphabet::Standard` supports (see `decode`). Other alphabets have
+    /// no such binary form, since they carry no general-purpose byte decoder.
+    fn to_binary_bytes(&self) -> Result<Vec<u8>, Base32Error> {
+        self.decode()
+    }
+
+    /// Reconstructs a standard-alphabet `Base32` from the bytes produced by `to_binary_bytes`.
+    fn from_binary_bytes(bytes: &[u8]) -> Base32 {
+        Base32::encode(bytes)
+    }
+
+    /// Encodes `data` as an RFC 4648 standard-alphabet (`A-Z2-7`) Base32 value, with `=` padding.
+    pub fn encode(data: &[u8]) -> Base32 {
+        const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+        let mut base32 = String::with_capacity((data.len() + 4) / 5 * 8);
+
+        for chunk in data.chunks(5) {
+            let mut buf = [0u8; 5];
+            buf[..chunk.len()].copy_from_slice(chunk);
+
+            let n: u64 = buf.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+
+            let significant = match chunk.len() {
+                5 => 8,
+                4 => 7,
+                3 => 5,
+                2 => 4,
+                1 => 2,
+                _ => unreachable!(),
+            };
+
+            let mut chars = [b'='; 8];
+
+            for (i, c) in chars.iter_mut().enumerate().take(significant) {
+                let shift = 35 - i * 5;
+                *c = ALPHABET[((n >> shift) & 0x1F) as usize];
+            }
+
+            base32.push_str(std::str::from_utf8(&chars).unwrap());
+        }
+
+        Base32 {
+            base32,
+            alphabet: Base32Alphabet::Standard,
+        }
+    }
+}
+
+#[inline]
+fn base32_char_value(c: u8) -> u8 {
+    match c {
+        b'A'..=b'Z' => c - b'A',
+        b'2'..=b'7' => c - b'2' + 26,
+        _ => unreachable!(),
     }
 }
 
+fn decode_base32(base32: &str) -> Result<Vec<u8>, Base32Error> {
+    let bytes = base32.as_bytes();
+
+    let mut output = Vec::with_capacity(bytes.len() / 8 * 5);
+
+    for chunk in bytes.chunks(8) {
+        let mut values = [0u8; 8];
+        let mut pad = 8 - chunk.len();
+
+        for (i, &c) in chunk.iter().enumerate() {
+            if c == b'=' {
+                pad += 1;
+            } else {
+                values[i] = base32_char_value(c);
+            }
+        }
+
+        let significant = 8 - pad;
+
+        let valid_bytes = match significant {
+            8 => 5,
+            7 => 4,
+            5 => 3,
+            4 => 2,
+            2 => 1,
+            _ => unreachable!(),
+        };
+
+        let n: u64 = values.iter().fold(0u64, |acc, &v| (acc << 5) | u64::from(v));
+
+        let unused_bits = 40 - valid_bytes * 8;
+
+        if unused_bits > 0 && n & ((1u64 << unused_bits) - 1) != 0 {
+            return Err(Base32Error::TrailingBits);
+        }
+
+        output.extend_from_slice(&n.to_be_bytes()[3..(3 + valid_bytes)]);
+    }
+
+    Ok(output)
+}
+
 impl Deref for Base32 {
     type Target = str;
 
@@ -92,7 +271,13 @@ impl Base32Validator {
     pub fn parse_string(&self, base32: String) -> Base32Result {
         let mut base32_inner = self.parse_inner(&base32)?;
 
-        base32_inner.base32 = base32;
+        // Crockford normalizes (strips separators, uppercases, remaps ambiguous letters), and
+        // `Optional` padding normalizes to the fully-padded canonical form, so in both cases
+        // `parse_inner` already filled in `base32_inner.base32` itself; every other combination
+        // leaves the input as-is.
+        if !self.canonicalizes() {
+            base32_inner.base32 = base32;
+        }
 
         Ok(base32_inner)
     }
@@ -100,21 +285,220 @@ impl Base32Validator {
     pub fn parse_str(&self, base32: &str) -> Base32Result {
         let mut base32_inner = self.parse_inner(base32)?;
 
-        base32_inner.base32.push_str(base32);
+        if !self.canonicalizes() {
+            base32_inner.base32.push_str(base32);
+        }
 
         Ok(base32_inner)
     }
 
     #[inline]
+    fn canonicalizes(&self) -> bool {
+        self.alphabet == Base32Alphabet::Crockford
+            || ((self.alphabet == Base32Alphabet::Standard
+                || self.alphabet == Base32Alphabet::ExtendedHex)
+                && self.padding == PaddingPolicy::Optional)
+    }
+
     fn parse_inner(&self, base32: &str) -> Base32Result {
-        if BASE32_RE.is_match(base32) {
-            Ok(Base32 {
-                base32: String::new(),
-            })
-        } else {
-            Err(Base32Error::IncorrectFormat)
+        match self.alphabet {
+            Base32Alphabet::Standard => {
+                let canonical =
+                    parse_padded_alphabet(base32, is_standard_base32_char, self.padding)?;
+
+                Ok(Base32 {
+                    base32: canonical,
+                    alphabet: Base32Alphabet::Standard,
+                })
+            }
+            Base32Alphabet::ExtendedHex => {
+                let canonical =
+                    parse_padded_alphabet(base32, is_extended_hex_base32_char, self.padding)?;
+
+                Ok(Base32 {
+                    base32: canonical,
+                    alphabet: Base32Alphabet::ExtendedHex,
+                })
+            }
+            Base32Alphabet::Crockford => {
+                let canonical = parse_crockford(base32)?;
+
+                Ok(Base32 {
+                    base32: canonical,
+                    alphabet: Base32Alphabet::Crockford,
+                })
+            }
+            Base32Alphabet::ZBase32 => {
+                if base32.is_empty() || !BASE32_Z_BASE_32_RE.is_match(&base32.to_lowercase()) {
+                    return Err(Base32Error::IncorrectFormat);
+                }
+
+                Ok(Base32 {
+                    base32: String::new(),
+                    alphabet: Base32Alphabet::ZBase32,
+                })
+            }
+        }
+    }
+}
+
+#[inline]
+fn is_standard_base32_char(b: u8) -> bool {
+    matches!(b, b'A'..=b'Z' | b'2'..=b'7')
+}
+
+#[inline]
+fn is_extended_hex_base32_char(b: u8) -> bool {
+    matches!(b, b'0'..=b'9' | b'A'..=b'V')
+}
+
+/// The number of `=` characters a final group of this many significant characters must be
+/// padded with to reach 8 (the same table `canonicalize_base32_padding` re-pads with).
+#[inline]
+fn base32_pad_len_for(significant: usize) -> Option<usize> {
+    match significant {
+        7 => Some(1),
+        5 => Some(3),
+        4 => Some(4),
+        2 => Some(6),
+        _ => None,
+    }
+}
+
+/// Validates `payload` against one alphabet's character class under the given `padding` policy in
+/// a single O(n) pass (no regex/backtracking), returning the canonical (fully-padded) form when
+/// `padding` is `Optional`. Every character is checked against `is_alphabet_char` as it's scanned,
+/// and any out-of-alphabet byte (or a character found after `=` padding has started) is reported
+/// by its exact byte offset via `Base32Error::InvalidCharacter`.
+fn parse_padded_alphabet(
+    payload: &str,
+    is_alphabet_char: fn(u8) -> bool,
+    padding: PaddingPolicy,
+) -> Result<String, Base32Error> {
+    if padding == PaddingPolicy::Forbidden && payload.contains('=') {
+        return Err(Base32Error::PaddingNotAllowed);
+    }
+
+    let bytes = payload.as_bytes();
+
+    // The index of the first `=`, if any; everything from there to the end must be `=`, and
+    // everything before it must be a valid alphabet character.
+    let mut first_pad = None;
+
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b'=' {
+            first_pad.get_or_insert(i);
+        } else if first_pad.is_some() || !is_alphabet_char(b) {
+            return Err(Base32Error::InvalidCharacter(i));
+        }
+    }
+
+    let significant_len = first_pad.unwrap_or(bytes.len());
+    let pad_len = bytes.len() - significant_len;
+    let last_group_significant = significant_len % 8;
+
+    if last_group_significant == 0 {
+        // A clean multiple of 8 significant characters never needs (or allows) padding.
+        if pad_len != 0 || (padding == PaddingPolicy::Required && significant_len == 0) {
+            return Err(Base32Error::IncorrectFormat);
+        }
+    } else {
+        let full_pad_len = match base32_pad_len_for(last_group_significant) {
+            Some(full_pad_len) => full_pad_len,
+            None => return Err(Base32Error::IncorrectFormat),
+        };
+
+        let pad_len_ok = match padding {
+            PaddingPolicy::Required => pad_len == full_pad_len,
+            PaddingPolicy::Forbidden => pad_len == 0,
+            PaddingPolicy::Optional => pad_len == 0 || pad_len == full_pad_len,
+        };
+
+        if !pad_len_ok {
+            return Err(Base32Error::IncorrectFormat);
+        }
+    }
+
+    if padding == PaddingPolicy::Optional {
+        Ok(canonicalize_base32_padding(payload))
+    } else {
+        Ok(String::new())
+    }
+}
+
+/// Pads `payload`'s final label group back out to its canonical `=`-padded length (a no-op if
+/// it's already fully padded or is a multiple of 8 characters with no final group at all).
+fn canonicalize_base32_padding(payload: &str) -> String {
+    let trimmed = payload.trim_end_matches('=');
+
+    let significant_in_last_group = trimmed.len() % 8;
+
+    if significant_in_last_group == 0 {
+        return trimmed.to_string();
+    }
+
+    let pad_len = match significant_in_last_group {
+        7 => 1,
+        5 => 3,
+        4 => 4,
+        2 => 6,
+        _ => unreachable!(),
+    };
+
+    let mut canonical = String::with_capacity(trimmed.len() + pad_len);
+    canonical.push_str(trimmed);
+    canonical.extend(std::iter::repeat('=').take(pad_len));
+    canonical
+}
+
+/// Normalizes and validates a Crockford base32 string: strips `-` separators, uppercases, reads
+/// `I`/`L` as `1` and `O` as `0`, then verifies the remaining data symbols and (if present) the
+/// trailing check symbol (`data value mod 37`).
+fn parse_crockford(input: &str) -> Result<String, Base32Error> {
+    let mut normalized = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        match c {
+            '-' => continue,
+            'i' | 'I' | 'l' | 'L' => normalized.push('1'),
+            'o' | 'O' => normalized.push('0'),
+            c => normalized.push(c.to_ascii_uppercase()),
         }
     }
+
+    if normalized.is_empty() {
+        return Err(Base32Error::IncorrectFormat);
+    }
+
+    let bytes = normalized.as_bytes();
+
+    let (data, check_symbol) = match bytes[bytes.len() - 1] {
+        b'*' | b'~' | b'$' | b'=' | b'U' if bytes.len() > 1 => {
+            (&bytes[..(bytes.len() - 1)], Some(bytes[bytes.len() - 1]))
+        }
+        _ => (bytes, None),
+    };
+
+    let mut value = 0u64;
+
+    for &c in data {
+        let digit = CROCKFORD_ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .ok_or(Base32Error::IncorrectFormat)?;
+
+        value = (value * 32 + digit as u64) % 37;
+    }
+
+    if let Some(check_symbol) = check_symbol {
+        let expected = CROCKFORD_CHECK_ALPHABET[value as usize];
+
+        if expected != check_symbol {
+            return Err(Base32Error::CheckSymbolMismatch);
+        }
+    }
+
+    Ok(normalized)
 }
 
 #[cfg(test)]
@@ -125,7 +509,7 @@ mod tests {
     fn test_base32_methods() {
         let base32 = "EB2GK43UEBWWK43TMFTWKCQK".to_string();
 
-        let bv = Base32Validator {};
+        let bv = Base32Validator::default();
 
         let base32 = bv.parse_string(base32).unwrap();
 
@@ -136,10 +520,173 @@ mod tests {
     fn test_base32_lv1() {
         let base32 = "EB2GK43UEBWWK43TMFTWKCQK".to_string();
 
-        let bv = Base32Validator {};
+        let bv = Base32Validator::default();
 
         bv.parse_string(base32).unwrap();
     }
+
+    #[test]
+    fn test_base32_encode() {
+        assert_eq!("MY======", Base32::encode(b"f").get_base32());
+        assert_eq!("MZXQ====", Base32::encode(b"fo").get_base32());
+        assert_eq!("MZXW6===", Base32::encode(b"foo").get_base32());
+        assert_eq!("MZXW6YQ=", Base32::encode(b"foob").get_base32());
+        assert_eq!("MZXW6YTB", Base32::encode(b"fooba").get_base32());
+        assert_eq!("MZXW6YTBOI======", Base32::encode(b"foobar").get_base32());
+    }
+
+    #[test]
+    fn test_base32_decode() {
+        let bv = Base32Validator::default();
+
+        assert_eq!(b"f".to_vec(), bv.parse_str("MY======").unwrap().decode().unwrap());
+        assert_eq!(b"fo".to_vec(), bv.parse_str("MZXQ====").unwrap().decode().unwrap());
+        assert_eq!(b"foo".to_vec(), bv.parse_str("MZXW6===").unwrap().decode().unwrap());
+        assert_eq!(b"foob".to_vec(), bv.parse_str("MZXW6YQ=").unwrap().decode().unwrap());
+        assert_eq!(b"fooba".to_vec(), bv.parse_str("MZXW6YTB").unwrap().decode().unwrap());
+        assert_eq!(
+            b"foobar".to_vec(),
+            bv.parse_str("MZXW6YTBOI======").unwrap().decode().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_base32_decode_trailing_bits() {
+        let bv = Base32Validator::default();
+
+        // The last character encodes a nonzero low bit that has no corresponding byte.
+        bv.parse_str("MZ======").unwrap().decode().unwrap_err();
+    }
+
+    #[test]
+    fn test_base32_extended_hex() {
+        let bv = Base32Validator {
+            alphabet: Base32Alphabet::ExtendedHex,
+            padding: PaddingPolicy::Required,
+        };
+
+        let base32 = bv.parse_str("CO======").unwrap();
+
+        assert_eq!("CO======", base32.get_base32());
+        assert_eq!(Base32Alphabet::ExtendedHex, base32.get_alphabet());
+
+        // The standard alphabet's "MY======" isn't valid extended-hex.
+        bv.parse_str("MY======").unwrap_err();
+    }
+
+    #[test]
+    fn test_base32_no_pad() {
+        let bv = Base32Validator {
+            alphabet: Base32Alphabet::Standard,
+            padding: PaddingPolicy::Forbidden,
+        };
+
+        bv.parse_str("MY").unwrap();
+        bv.parse_str("MY======").unwrap_err();
+    }
+
+    #[test]
+    fn test_base32_z_base_32() {
+        let bv = Base32Validator {
+            alphabet: Base32Alphabet::ZBase32,
+            padding: PaddingPolicy::Required,
+        };
+
+        let base32 = bv.parse_str("ybndrfg8").unwrap();
+
+        assert_eq!(Base32Alphabet::ZBase32, base32.get_alphabet());
+
+        bv.parse_str("MY======").unwrap_err();
+    }
+
+    #[test]
+    fn test_base32_crockford() {
+        let bv = Base32Validator {
+            alphabet: Base32Alphabet::Crockford,
+            padding: PaddingPolicy::Required,
+        };
+
+        // Hyphens are stripped, `i`/`l`/`o` are remapped, and the result is uppercased.
+        let base32 = bv.parse_str("fv-il-o0").unwrap();
+
+        assert_eq!("FV1100", base32.get_base32());
+        assert_eq!(Base32Alphabet::Crockford, base32.get_alphabet());
+    }
+
+    #[test]
+    fn test_base32_crockford_check_symbol() {
+        let bv = Base32Validator {
+            alphabet: Base32Alphabet::Crockford,
+            padding: PaddingPolicy::Required,
+        };
+
+        // "10" has value (1 * 32 + 0) mod 37 == 32, whose check symbol is `*`.
+        let base32 = bv.parse_str("10*").unwrap();
+
+        assert_eq!("10*", base32.get_base32());
+
+        bv.parse_str("10U").unwrap_err();
+    }
+
+    #[test]
+    fn test_base32_padding_forbidden() {
+        let bv = Base32Validator {
+            alphabet: Base32Alphabet::Standard,
+            padding: PaddingPolicy::Forbidden,
+        };
+
+        assert_eq!(
+            Base32Error::PaddingNotAllowed,
+            bv.parse_str("MY======").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_base32_invalid_character_offset() {
+        let bv = Base32Validator::default();
+
+        assert_eq!(
+            Base32Error::InvalidCharacter(2),
+            bv.parse_str("MY0=====").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_base32_padding_optional() {
+        let bv = Base32Validator {
+            alphabet: Base32Alphabet::Standard,
+            padding: PaddingPolicy::Optional,
+        };
+
+        let padded = bv.parse_str("MY======").unwrap();
+        let unpadded = bv.parse_str("MY").unwrap();
+
+        assert_eq!("MY======", padded.get_base32());
+        assert_eq!("MY======", unpadded.get_base32());
+    }
+
+    #[test]
+    fn test_base32_binary_bytes_standard_round_trip() {
+        let bv = Base32Validator::default();
+
+        let base32 = bv.parse_str("MZXW6YTBOI======").unwrap();
+        let binary = base32.to_binary_bytes().unwrap();
+
+        assert_eq!(b"foobar".to_vec(), binary);
+        assert_eq!("MZXW6YTBOI======", Base32::from_binary_bytes(&binary).get_base32());
+    }
+
+    #[test]
+    fn test_base32_binary_bytes_non_standard_unsupported() {
+        let bv = Base32Validator {
+            alphabet: Base32Alphabet::ExtendedHex,
+            padding: PaddingPolicy::Required,
+        };
+
+        let base32 = bv.parse_str("CO======").unwrap();
+
+        base32.to_binary_bytes().unwrap_err();
+    }
 }
 
 // Base32's wrapper struct is itself
@@ -171,7 +718,7 @@ impl Base32 {
 
     #[inline]
     fn create_validator() -> Base32Validator {
-        Base32Validator {}
+        Base32Validator::default()
     }
 }
 
@@ -204,6 +751,35 @@ impl<'a> ::rocket::request::FromFormValue<'a> for Base32 {
     }
 }
 
+/// Reads a streamed/multipart body as base32, without buffering the whole thing into a `String`
+/// up front: `Capped::is_complete()` is `false` when the incoming data was cut off at the
+/// request's configured `string` size limit.
+#[cfg(feature = "rocketly")]
+impl ::rocket::data::FromDataSimple for Capped<Base32> {
+    type Error = Base32Error;
+
+    fn from_data(request: &::rocket::Request, data: ::rocket::Data) -> ::rocket::data::Outcome<Self, Self::Error> {
+        let limit = request.limits().get("string").unwrap_or(256 * 1024);
+
+        let capped = match read_capped_string(data, limit) {
+            Ok(capped) => capped,
+            Err(_) => {
+                return ::rocket::Outcome::Failure((
+                    ::rocket::http::Status::BadRequest,
+                    Base32Error::IncorrectFormat,
+                ));
+            }
+        };
+
+        let complete = capped.is_complete();
+
+        match Base32::from_string(capped.into_value()) {
+            Ok(value) => ::rocket::Outcome::Success(Capped::new(value, complete)),
+            Err(err) => ::rocket::Outcome::Failure((::rocket::http::Status::UnprocessableEntity, err)),
+        }
+    }
+}
+
 #[cfg(feature = "serdely")]
 struct StringVisitor;
 
@@ -213,7 +789,7 @@ impl<'de> ::serde::de::Visitor<'de> for StringVisitor {
 
     #[inline]
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a Base32 string")
+        formatter.write_str("a Base32 string or, for binary formats, its decoded bytes")
     }
 
     #[inline]
@@ -229,6 +805,23 @@ impl<'de> ::serde::de::Visitor<'de> for StringVisitor {
         E: ::serde::de::Error, {
         Base32::from_string(v).map_err(|err| E::custom(err.to_string()))
     }
+
+    // Binary formats (bincode, etc.) round-trip the decoded bytes instead of the base32 text; see
+    // the `Serialize` impl below. Since only `Base32Alphabet::Standard` supports `decode`, the
+    // reconstructed value is always standard-alphabet.
+    #[inline]
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        Ok(Base32::from_binary_bytes(v))
+    }
+
+    #[inline]
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        Ok(Base32::from_binary_bytes(&v))
+    }
 }
 
 #[cfg(feature = "serdely")]
@@ -237,7 +830,11 @@ impl<'de> ::serde::Deserialize<'de> for Base32 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: ::serde::Deserializer<'de>, {
-        deserializer.deserialize_str(StringVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StringVisitor)
+        } else {
+            deserializer.deserialize_bytes(StringVisitor)
+        }
     }
 }
 
@@ -247,6 +844,22 @@ impl ::serde::Serialize for Base32 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: ::serde::Serializer, {
-        serializer.serialize_str(&self.base32)
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.base32)
+        } else {
+            // Binary formats only support the compact byte form for the standard alphabet (the
+            // only one `decode` understands); other alphabets have no well-defined byte decoding
+            // and are rejected with a clear error instead of silently losing data.
+            let decoded = self.to_binary_bytes().map_err(|_| {
+                <S::Error as ::serde::ser::Error>::custom(format!(
+                    "binary (non-human-readable) serialization of Base32 is only supported for \
+                     the {:?} alphabet, not {:?}",
+                    Base32Alphabet::Standard,
+                    self.alphabet
+                ))
+            })?;
+
+            serializer.serialize_bytes(&decoded)
+        }
     }
 }
@@ -3,6 +3,9 @@ extern crate regex;
 use self::regex::Regex;
 use super::{Validated, ValidatedWrapper};
 
+#[cfg(feature = "rocketly")]
+use super::{read_capped_string, Capped};
+
 use std::error::Error;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::ops::Deref;
@@ -15,6 +18,114 @@ lazy_static! {
         )
         .unwrap()
     };
+    static ref BASE64_URL_SAFE_RE: Regex = {
+        Regex::new(
+            "^([-A-Za-z0-9_]{4})*(([-A-Za-z0-9_]{3}=)|([-A-Za-z0-9_]{2}==))?$",
+        )
+        .unwrap()
+    };
+    static ref BASE64_NO_PAD_RE: Regex = {
+        Regex::new("^([A-Za-z0-9+/]{4})*([A-Za-z0-9+/]{2,3})?$").unwrap()
+    };
+    static ref BASE64_URL_SAFE_NO_PAD_RE: Regex = {
+        Regex::new("^([-A-Za-z0-9_]{4})*([-A-Za-z0-9_]{2,3})?$").unwrap()
+    };
+    static ref BASE64_OPTIONAL_PAD_RE: Regex = {
+        Regex::new(
+            "^([A-Za-z0-9+/]{4})*(([A-Za-z0-9+/]{2}=?=?)|([A-Za-z0-9+/]{3}=?))?$",
+        )
+        .unwrap()
+    };
+    static ref BASE64_URL_SAFE_OPTIONAL_PAD_RE: Regex = {
+        Regex::new(
+            "^([-A-Za-z0-9_]{4})*(([-A-Za-z0-9_]{2}=?=?)|([-A-Za-z0-9_]{3}=?))?$",
+        )
+        .unwrap()
+    };
+}
+
+/// The padding policy used to validate a base64 string.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Padding {
+    /// Canonical `=` padding is mandatory (the historical, default behavior).
+    Required,
+    /// No `=` padding is allowed, as used by `base64::STANDARD_NO_PAD` and JWTs.
+    Forbidden,
+    /// `=` padding may be present or omitted.
+    Optional,
+}
+
+impl Default for Padding {
+    #[inline]
+    fn default() -> Self {
+        Padding::Required
+    }
+}
+
+/// The newline style used to separate lines in a multiline (MIME/PEM) base64 block.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Newline {
+    /// A single `\n` character.
+    LF,
+    /// A `\r\n` sequence, as used by MIME.
+    CRLF,
+}
+
+impl Newline {
+    #[inline]
+    fn as_str(self) -> &'static str {
+        match self {
+            Newline::LF => "\n",
+            Newline::CRLF => "\r\n",
+        }
+    }
+}
+
+/// Configuration for validating a base64 string wrapped at fixed column widths, such as PEM
+/// (64 columns) or MIME (76 columns).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct MultilineConfig {
+    pub newline: Newline,
+    /// When set, every line except the last one must be exactly this many characters long.
+    pub line_length: Option<usize>,
+}
+
+fn strip_lines(base64: &str, config: MultilineConfig) -> Result<String, Base64Error> {
+    let sep = config.newline.as_str();
+
+    let lines: Vec<&str> = base64.split(sep).collect();
+
+    let last = lines.len() - 1;
+
+    let mut payload = String::with_capacity(base64.len());
+
+    for (i, line) in lines.into_iter().enumerate() {
+        if let Some(line_length) = config.line_length {
+            if i != last && line.len() != line_length {
+                return Err(Base64Error::IncorrectFormat);
+            }
+        }
+
+        payload.push_str(line);
+    }
+
+    Ok(payload)
+}
+
+/// The alphabet used to validate a base64 string.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Base64CharacterSet {
+    /// The standard alphabet (`+` and `/`).
+    Standard,
+    /// The URL- and filename-safe alphabet (`-` and `_`), as used by JWTs.
+    UrlSafe,
+}
+
+impl Default for Base64CharacterSet {
+    #[inline]
+    fn default() -> Self {
+        Base64CharacterSet::Standard
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -41,12 +152,19 @@ impl From<Utf8Error> for Base64Error {
 
 pub type Base64Result = Result<Base64, Base64Error>;
 
-#[derive(Debug, PartialEq)]
-pub struct Base64Validator {}
+#[derive(Debug, PartialEq, Default)]
+pub struct Base64Validator {
+    pub char_set: Base64CharacterSet,
+    pub padding: Padding,
+    /// When set, the input is treated as a PEM/MIME-style base64 block wrapped across lines.
+    pub multiline: Option<MultilineConfig>,
+}
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Base64 {
     base64: String,
+    char_set: Base64CharacterSet,
+    multiline: Option<MultilineConfig>,
 }
 
 impl Base64 {
@@ -65,8 +183,75 @@ impl Base64 {
     pub unsafe fn from_string_unchecked(base64: String) -> Base64 {
         Base64 {
             base64,
+            char_set: Base64CharacterSet::Standard,
+            multiline: None,
+        }
+    }
+
+    /// Decodes the validated base64 string into its raw bytes, ignoring line breaks.
+    pub fn decode(&self) -> Result<Vec<u8>, Base64Error> {
+        match self.multiline {
+            Some(config) => decode_base64(&strip_lines(&self.base64, config)?, self.char_set),
+            None => decode_base64(&self.base64, self.char_set),
+        }
+    }
+
+    /// Decodes the validated base64 string into bytes, then validates them as UTF-8.
+    pub fn decode_to_string(&self) -> Result<String, Base64Error> {
+        let bytes = self.decode()?;
+
+        Ok(std::str::from_utf8(&bytes)?.to_string())
+    }
+}
+
+#[inline]
+fn base64_char_value(c: u8, char_set: Base64CharacterSet) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' if char_set == Base64CharacterSet::Standard => Some(62),
+        b'/' if char_set == Base64CharacterSet::Standard => Some(63),
+        b'-' if char_set == Base64CharacterSet::UrlSafe => Some(62),
+        b'_' if char_set == Base64CharacterSet::UrlSafe => Some(63),
+        _ => None,
+    }
+}
+
+fn decode_base64(base64: &str, char_set: Base64CharacterSet) -> Result<Vec<u8>, Base64Error> {
+    let bytes = base64.as_bytes();
+
+    let mut output = Vec::with_capacity(bytes.len() / 4 * 3);
+
+    for chunk in bytes.chunks(4) {
+        let mut values = [0u8; 4];
+        let mut pad = 4 - chunk.len();
+
+        for (i, &c) in chunk.iter().enumerate() {
+            if c == b'=' {
+                pad += 1;
+            } else {
+                values[i] = base64_char_value(c, char_set).ok_or(Base64Error::IncorrectFormat)?;
+            }
+        }
+
+        let n = (u32::from(values[0]) << 18)
+            | (u32::from(values[1]) << 12)
+            | (u32::from(values[2]) << 6)
+            | u32::from(values[3]);
+
+        output.push((n >> 16) as u8);
+
+        if pad < 2 {
+            output.push((n >> 8) as u8);
+        }
+
+        if pad < 1 {
+            output.push(n as u8);
         }
     }
+
+    Ok(output)
 }
 
 impl Deref for Base64 {
@@ -121,9 +306,38 @@ impl Base64Validator {
 
     #[inline]
     fn parse_inner(&self, base64: &str) -> Base64Result {
-        if BASE64_RE.is_match(base64) {
+        let owned_payload;
+
+        let payload: &str = match self.multiline {
+            Some(config) => {
+                owned_payload = strip_lines(base64, config)?;
+                &owned_payload
+            }
+            None => base64,
+        };
+
+        let is_match = match (self.char_set, self.padding) {
+            (Base64CharacterSet::Standard, Padding::Required) => BASE64_RE.is_match(payload),
+            (Base64CharacterSet::Standard, Padding::Forbidden) => {
+                BASE64_NO_PAD_RE.is_match(payload)
+            }
+            (Base64CharacterSet::Standard, Padding::Optional) => {
+                BASE64_OPTIONAL_PAD_RE.is_match(payload)
+            }
+            (Base64CharacterSet::UrlSafe, Padding::Required) => BASE64_URL_SAFE_RE.is_match(payload),
+            (Base64CharacterSet::UrlSafe, Padding::Forbidden) => {
+                BASE64_URL_SAFE_NO_PAD_RE.is_match(payload)
+            }
+            (Base64CharacterSet::UrlSafe, Padding::Optional) => {
+                BASE64_URL_SAFE_OPTIONAL_PAD_RE.is_match(payload)
+            }
+        };
+
+        if is_match {
             Ok(Base64 {
                 base64: String::new(),
+                char_set: self.char_set,
+                multiline: self.multiline,
             })
         } else {
             Err(Base64Error::IncorrectFormat)
@@ -139,7 +353,7 @@ mod tests {
     fn test_base64_methods() {
         let base64 = "IHRlc3QgbWVzc2FnZQoK".to_string();
 
-        let bv = Base64Validator {};
+        let bv = Base64Validator::default();
 
         let base64 = bv.parse_string(base64).unwrap();
 
@@ -150,10 +364,80 @@ mod tests {
     fn test_base64_lv1() {
         let base64 = "IHRlc3QgbWVzc2FnZQoK".to_string();
 
-        let bv = Base64Validator {};
+        let bv = Base64Validator::default();
+
+        bv.parse_string(base64).unwrap();
+    }
+
+    #[test]
+    fn test_base64_url_safe() {
+        let base64 = "SGVsbG8tV29ybGRfMTIz".to_string();
+
+        let bv = Base64Validator {
+            char_set: Base64CharacterSet::UrlSafe,
+            padding: Padding::Required,
+            multiline: None,
+        };
+
+        bv.parse_string(base64).unwrap();
+    }
+
+    #[test]
+    fn test_base64_no_pad() {
+        let base64 = "aGVsbG8".to_string();
+
+        let bv = Base64Validator {
+            char_set: Base64CharacterSet::Standard,
+            padding: Padding::Forbidden,
+            multiline: None,
+        };
 
         bv.parse_string(base64).unwrap();
     }
+
+    #[test]
+    fn test_base64_optional_pad() {
+        let bv = Base64Validator {
+            char_set: Base64CharacterSet::Standard,
+            padding: Padding::Optional,
+            multiline: None,
+        };
+
+        bv.parse_str("aGVsbG8").unwrap();
+        bv.parse_str("aGVsbG8=").unwrap();
+    }
+
+    #[test]
+    fn test_base64_multiline() {
+        let base64 = "aGVsbG8t\nd29ybGQh".to_string();
+
+        let bv = Base64Validator {
+            char_set: Base64CharacterSet::Standard,
+            padding: Padding::Required,
+            multiline: Some(MultilineConfig {
+                newline: Newline::LF,
+                line_length: Some(8),
+            }),
+        };
+
+        let base64 = bv.parse_string(base64).unwrap();
+
+        assert_eq!(b"hello-world!".to_vec(), base64.decode().unwrap());
+    }
+
+    #[test]
+    fn test_base64_decode() {
+        let base64 = Base64::from_str("IHRlc3QgbWVzc2FnZQoK").unwrap();
+
+        assert_eq!(b" test message\n\n".to_vec(), base64.decode().unwrap());
+    }
+
+    #[test]
+    fn test_base64_decode_to_string() {
+        let base64 = Base64::from_str("aGVsbG8=").unwrap();
+
+        assert_eq!("hello", base64.decode_to_string().unwrap());
+    }
 }
 
 // Base64's wrapper struct is itself
@@ -184,7 +468,7 @@ impl Base64 {
     }
 
     fn create_validator() -> Base64Validator {
-        Base64Validator {}
+        Base64Validator::default()
     }
 }
 
@@ -217,6 +501,35 @@ impl<'a> ::rocket::request::FromParam<'a> for Base64 {
     }
 }
 
+/// Reads a streamed/multipart body as base64, without buffering the whole thing into a `String`
+/// up front: `Capped::is_complete()` is `false` when the incoming data was cut off at the
+/// request's configured `string` size limit.
+#[cfg(feature = "rocketly")]
+impl ::rocket::data::FromDataSimple for Capped<Base64> {
+    type Error = Base64Error;
+
+    fn from_data(request: &::rocket::Request, data: ::rocket::Data) -> ::rocket::data::Outcome<Self, Self::Error> {
+        let limit = request.limits().get("string").unwrap_or(256 * 1024);
+
+        let capped = match read_capped_string(data, limit) {
+            Ok(capped) => capped,
+            Err(_) => {
+                return ::rocket::Outcome::Failure((
+                    ::rocket::http::Status::BadRequest,
+                    Base64Error::IncorrectFormat,
+                ));
+            }
+        };
+
+        let complete = capped.is_complete();
+
+        match Base64::from_string(capped.into_value()) {
+            Ok(value) => ::rocket::Outcome::Success(Capped::new(value, complete)),
+            Err(err) => ::rocket::Outcome::Failure((::rocket::http::Status::UnprocessableEntity, err)),
+        }
+    }
+}
+
 #[cfg(feature = "serdely")]
 struct StringVisitor;
 
@@ -0,0 +1,363 @@
+extern crate regex;
+
+use self::regex::Regex;
+use super::base64::Base64;
+use super::{Validated, ValidatedWrapper};
+
+#[cfg(feature = "rocketly")]
+use super::{read_capped_string, Capped};
+
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::Deref;
+use std::str::{FromStr, Utf8Error};
+
+lazy_static! {
+    static ref MODIFIED_BASE64_RE: Regex = {
+        Regex::new(
+            r"^(?:[-A-Za-z0-9_]{4})*(?:[-_A-Za-z0-9]{2}~~|[-_A-Za-z0-9]{3}~)?$",
+        )
+        .unwrap()
+    };
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ModifiedBase64Error {
+    IncorrectFormat,
+    UTF8Error(Utf8Error),
+}
+
+impl Display for ModifiedBase64Error {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for ModifiedBase64Error {}
+
+impl From<Utf8Error> for ModifiedBase64Error {
+    #[inline]
+    fn from(err: Utf8Error) -> Self {
+        ModifiedBase64Error::UTF8Error(err)
+    }
+}
+
+pub type ModifiedBase64Result = Result<ModifiedBase64, ModifiedBase64Error>;
+
+/// Validates base64 that has been made safe to embed in a URL path segment by mapping
+/// `+` -> `-`, `/` -> `_` and `=` -> `~`.
+#[derive(Debug, PartialEq)]
+pub struct ModifiedBase64Validator {}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ModifiedBase64 {
+    modified_base64: String,
+}
+
+impl ModifiedBase64 {
+    #[inline]
+    pub fn get_modified_base64(&self) -> &str {
+        &self.modified_base64
+    }
+
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.modified_base64
+    }
+
+    #[allow(clippy::missing_safety_doc)]
+    #[inline]
+    pub unsafe fn from_string_unchecked(modified_base64: String) -> ModifiedBase64 {
+        ModifiedBase64 {
+            modified_base64,
+        }
+    }
+
+    /// Converts this modified-base64 token back into a standard `Base64`.
+    pub fn into_base64(self) -> Base64 {
+        let base64 = translate(&self.modified_base64, ToStandard);
+
+        unsafe { Base64::from_string_unchecked(base64) }
+    }
+
+    /// Converts a standard `Base64` into its URL-path-safe modified-base64 form.
+    pub fn from_base64(base64: Base64) -> ModifiedBase64 {
+        let modified_base64 = translate(base64.get_base64(), ToModified);
+
+        unsafe { ModifiedBase64::from_string_unchecked(modified_base64) }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ToStandard;
+
+#[derive(Clone, Copy)]
+struct ToModified;
+
+trait Translation {
+    fn translate(&self, c: char) -> char;
+}
+
+impl Translation for ToStandard {
+    #[inline]
+    fn translate(&self, c: char) -> char {
+        match c {
+            '-' => '+',
+            '_' => '/',
+            '~' => '=',
+            c => c,
+        }
+    }
+}
+
+impl Translation for ToModified {
+    #[inline]
+    fn translate(&self, c: char) -> char {
+        match c {
+            '+' => '-',
+            '/' => '_',
+            '=' => '~',
+            c => c,
+        }
+    }
+}
+
+fn translate<D: Translation>(s: &str, direction: D) -> String {
+    s.chars().map(|c| direction.translate(c)).collect()
+}
+
+impl Deref for ModifiedBase64 {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.modified_base64
+    }
+}
+
+impl Validated for ModifiedBase64 {}
+
+impl Debug for ModifiedBase64 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        impl_debug_for_tuple_struct!(ModifiedBase64, f, self, let .0 = self.modified_base64);
+    }
+}
+
+impl Display for ModifiedBase64 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.modified_base64)?;
+        Ok(())
+    }
+}
+
+impl ModifiedBase64Validator {
+    #[inline]
+    pub fn is_modified_base64(&self, modified_base64: &str) -> bool {
+        self.parse_inner(modified_base64).is_ok()
+    }
+
+    pub fn parse_string(&self, modified_base64: String) -> ModifiedBase64Result {
+        let mut modified_base64_inner = self.parse_inner(&modified_base64)?;
+
+        modified_base64_inner.modified_base64 = modified_base64;
+
+        Ok(modified_base64_inner)
+    }
+
+    pub fn parse_str(&self, modified_base64: &str) -> ModifiedBase64Result {
+        let mut modified_base64_inner = self.parse_inner(modified_base64)?;
+
+        modified_base64_inner.modified_base64.push_str(modified_base64);
+
+        Ok(modified_base64_inner)
+    }
+
+    #[inline]
+    fn parse_inner(&self, modified_base64: &str) -> ModifiedBase64Result {
+        if MODIFIED_BASE64_RE.is_match(modified_base64) {
+            Ok(ModifiedBase64 {
+                modified_base64: String::new(),
+            })
+        } else {
+            Err(ModifiedBase64Error::IncorrectFormat)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modified_base64_methods() {
+        let modified_base64 = "SGVsbG8tV29ybGRfMTIz".to_string();
+
+        let bv = ModifiedBase64Validator {};
+
+        let modified_base64 = bv.parse_string(modified_base64).unwrap();
+
+        assert_eq!("SGVsbG8tV29ybGRfMTIz", modified_base64.get_modified_base64());
+    }
+
+    #[test]
+    fn test_modified_base64_lv1() {
+        let modified_base64 = "aGVsbG8~".to_string();
+
+        let bv = ModifiedBase64Validator {};
+
+        bv.parse_string(modified_base64).unwrap();
+    }
+
+    #[test]
+    fn test_modified_base64_round_trip() {
+        let base64 = Base64::from_str("aGVsbG8=").unwrap();
+
+        let modified = ModifiedBase64::from_base64(base64.clone());
+
+        assert_eq!("aGVsbG8~", modified.get_modified_base64());
+
+        let round_tripped = modified.into_base64();
+
+        assert_eq!(base64.get_base64(), round_tripped.get_base64());
+    }
+}
+
+// ModifiedBase64's wrapper struct is itself
+impl ValidatedWrapper for ModifiedBase64 {
+    type Error = ModifiedBase64Error;
+
+    #[inline]
+    fn from_string(modified_base64: String) -> Result<Self, Self::Error> {
+        ModifiedBase64::from_string(modified_base64)
+    }
+
+    #[inline]
+    fn from_str(modified_base64: &str) -> Result<Self, Self::Error> {
+        ModifiedBase64::from_str(modified_base64)
+    }
+}
+
+impl ModifiedBase64 {
+    #[inline]
+    pub fn from_string(modified_base64: String) -> Result<Self, ModifiedBase64Error> {
+        ModifiedBase64::create_validator().parse_string(modified_base64)
+    }
+
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(modified_base64: &str) -> Result<Self, ModifiedBase64Error> {
+        ModifiedBase64::create_validator().parse_str(modified_base64)
+    }
+
+    fn create_validator() -> ModifiedBase64Validator {
+        ModifiedBase64Validator {}
+    }
+}
+
+impl FromStr for ModifiedBase64 {
+    type Err = ModifiedBase64Error;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ModifiedBase64::from_str(s)
+    }
+}
+
+#[cfg(feature = "rocketly")]
+impl<'a> ::rocket::request::FromFormValue<'a> for ModifiedBase64 {
+    type Error = ModifiedBase64Error;
+
+    #[inline]
+    fn from_form_value(form_value: &'a ::rocket::http::RawStr) -> Result<Self, Self::Error> {
+        ModifiedBase64::from_string(form_value.url_decode()?)
+    }
+}
+
+#[cfg(feature = "rocketly")]
+impl<'a> ::rocket::request::FromParam<'a> for ModifiedBase64 {
+    type Error = ModifiedBase64Error;
+
+    #[inline]
+    fn from_param(param: &'a ::rocket::http::RawStr) -> Result<Self, Self::Error> {
+        ModifiedBase64::from_string(param.url_decode()?)
+    }
+}
+
+/// Reads a streamed/multipart body as URL-safe base64, without buffering the whole thing into a
+/// `String` up front: `Capped::is_complete()` is `false` when the incoming data was cut off at
+/// the request's configured `string` size limit.
+#[cfg(feature = "rocketly")]
+impl ::rocket::data::FromDataSimple for Capped<ModifiedBase64> {
+    type Error = ModifiedBase64Error;
+
+    fn from_data(request: &::rocket::Request, data: ::rocket::Data) -> ::rocket::data::Outcome<Self, Self::Error> {
+        let limit = request.limits().get("string").unwrap_or(256 * 1024);
+
+        let capped = match read_capped_string(data, limit) {
+            Ok(capped) => capped,
+            Err(_) => {
+                return ::rocket::Outcome::Failure((
+                    ::rocket::http::Status::BadRequest,
+                    ModifiedBase64Error::IncorrectFormat,
+                ));
+            }
+        };
+
+        let complete = capped.is_complete();
+
+        match ModifiedBase64::from_string(capped.into_value()) {
+            Ok(value) => ::rocket::Outcome::Success(Capped::new(value, complete)),
+            Err(err) => ::rocket::Outcome::Failure((::rocket::http::Status::UnprocessableEntity, err)),
+        }
+    }
+}
+
+#[cfg(feature = "serdely")]
+struct StringVisitor;
+
+#[cfg(feature = "serdely")]
+impl<'de> ::serde::de::Visitor<'de> for StringVisitor {
+    type Value = ModifiedBase64;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a ModifiedBase64 string")
+    }
+
+    #[inline]
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        ModifiedBase64::from_str(v).map_err(|err| E::custom(err.to_string()))
+    }
+
+    #[inline]
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        ModifiedBase64::from_string(v).map_err(|err| E::custom(err.to_string()))
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl<'de> ::serde::Deserialize<'de> for ModifiedBase64 {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>, {
+        deserializer.deserialize_string(StringVisitor)
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl ::serde::Serialize for ModifiedBase64 {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer, {
+        serializer.serialize_str(&self.modified_base64)
+    }
+}
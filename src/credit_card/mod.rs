@@ -0,0 +1,513 @@
+use super::{Validated, ValidatedWrapper, ValidatorOption};
+
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// Returns the value of the `len`-digit decimal prefix of `digits`, or `0` if `digits` is
+/// shorter than `len`.
+fn prefix(digits: &[u8], len: usize) -> u32 {
+    if digits.len() < len {
+        return 0;
+    }
+
+    let mut value = 0u32;
+
+    for &b in &digits[..len] {
+        value = value * 10 + u32::from(b - b'0');
+    }
+
+    value
+}
+
+/// Runs the Luhn checksum over `digits` (ASCII digit bytes, most significant digit first):
+/// starting from the rightmost digit and moving left, every second digit is doubled, and if a
+/// doubled value exceeds 9, 9 is subtracted from it; the number is valid iff the sum of all the
+/// resulting digits is divisible by 10.
+fn luhn_is_valid(digits: &[u8]) -> bool {
+    let mut sum = 0u32;
+
+    for (i, &b) in digits.iter().rev().enumerate() {
+        let mut digit = u32::from(b - b'0');
+
+        if i % 2 == 1 {
+            digit *= 2;
+
+            if digit > 9 {
+                digit -= 9;
+            }
+        }
+
+        sum += digit;
+    }
+
+    sum % 10 == 0
+}
+
+/// The card brand detected from a number's IIN/BIN prefix and length.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CreditCardBrand {
+    Visa,
+    MasterCard,
+    AmericanExpress,
+    DinersClub,
+    Discover,
+    JCB,
+    UnionPay,
+    Unknown,
+}
+
+impl CreditCardBrand {
+    fn detect(digits: &[u8]) -> CreditCardBrand {
+        let len = digits.len();
+        let p1 = prefix(digits, 1);
+        let p2 = prefix(digits, 2);
+        let p3 = prefix(digits, 3);
+        let p4 = prefix(digits, 4);
+        let p6 = prefix(digits, 6);
+
+        if p1 == 4 {
+            CreditCardBrand::Visa
+        } else if (51..=55).contains(&p2) || (2221..=2720).contains(&p4) {
+            CreditCardBrand::MasterCard
+        } else if (p2 == 34 || p2 == 37) && len == 15 {
+            CreditCardBrand::AmericanExpress
+        } else if p4 == 6011 || p2 == 65 || (644..=649).contains(&p3) || (622126..=622925).contains(&p6) {
+            CreditCardBrand::Discover
+        } else if (300..=305).contains(&p3) || p2 == 36 || p2 == 38 || p2 == 39 {
+            CreditCardBrand::DinersClub
+        } else if (3528..=3589).contains(&p4) {
+            CreditCardBrand::JCB
+        } else if p2 == 62 {
+            CreditCardBrand::UnionPay
+        } else {
+            CreditCardBrand::Unknown
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CreditCardError {
+    IncorrectFormat,
+    IncorrectChecksum,
+    BrandNotAllow,
+}
+
+impl Display for CreditCardError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for CreditCardError {}
+
+pub type CreditCardResult = Result<CreditCard, CreditCardError>;
+
+/// Restricts which card brands `CreditCardValidator::parse_str`/`parse_string` will accept.
+/// Each field is independent, mirroring the detected brand of the number being parsed.
+#[derive(Debug, PartialEq)]
+pub struct CreditCardValidator {
+    pub visa: ValidatorOption,
+    pub mastercard: ValidatorOption,
+    pub american_express: ValidatorOption,
+    pub diners_club: ValidatorOption,
+    pub discover: ValidatorOption,
+    pub jcb: ValidatorOption,
+    pub union_pay: ValidatorOption,
+    pub other: ValidatorOption,
+}
+
+impl CreditCardValidator {
+    fn option_for(&self, brand: CreditCardBrand) -> &ValidatorOption {
+        match brand {
+            CreditCardBrand::Visa => &self.visa,
+            CreditCardBrand::MasterCard => &self.mastercard,
+            CreditCardBrand::AmericanExpress => &self.american_express,
+            CreditCardBrand::DinersClub => &self.diners_club,
+            CreditCardBrand::Discover => &self.discover,
+            CreditCardBrand::JCB => &self.jcb,
+            CreditCardBrand::UnionPay => &self.union_pay,
+            CreditCardBrand::Unknown => &self.other,
+        }
+    }
+
+    pub fn is_credit_card(&self, number: &str) -> bool {
+        self.parse_inner(number).is_ok()
+    }
+
+    pub fn parse_string(&self, number: String) -> CreditCardResult {
+        self.parse_inner(&number)
+    }
+
+    pub fn parse_str(&self, number: &str) -> CreditCardResult {
+        self.parse_inner(number)
+    }
+
+    fn parse_inner(&self, number: &str) -> CreditCardResult {
+        let mut digits = String::with_capacity(number.len());
+
+        for c in number.chars() {
+            match c {
+                ' ' | '-' => continue,
+                '0'..='9' => digits.push(c),
+                _ => return Err(CreditCardError::IncorrectFormat),
+            }
+        }
+
+        if digits.len() < 12 || digits.len() > 19 {
+            return Err(CreditCardError::IncorrectFormat);
+        }
+
+        if !luhn_is_valid(digits.as_bytes()) {
+            return Err(CreditCardError::IncorrectChecksum);
+        }
+
+        let brand = CreditCardBrand::detect(digits.as_bytes());
+
+        if self.option_for(brand).not_allow() {
+            return Err(CreditCardError::BrandNotAllow);
+        }
+
+        Ok(CreditCard {
+            number: digits,
+            brand,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CreditCard {
+    number: String,
+    brand: CreditCardBrand,
+}
+
+impl CreditCard {
+    pub fn get_number(&self) -> &str {
+        &self.number
+    }
+
+    pub fn get_brand(&self) -> CreditCardBrand {
+        self.brand
+    }
+
+    pub fn into_string(self) -> String {
+        self.number
+    }
+}
+
+impl Deref for CreditCard {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.number
+    }
+}
+
+impl Validated for CreditCard {}
+
+impl Display for CreditCard {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.number)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allow_all() -> CreditCardValidator {
+        CreditCardValidator {
+            visa: ValidatorOption::Allow,
+            mastercard: ValidatorOption::Allow,
+            american_express: ValidatorOption::Allow,
+            diners_club: ValidatorOption::Allow,
+            discover: ValidatorOption::Allow,
+            jcb: ValidatorOption::Allow,
+            union_pay: ValidatorOption::Allow,
+            other: ValidatorOption::Allow,
+        }
+    }
+
+    #[test]
+    fn test_credit_card_visa() {
+        let cv = allow_all();
+
+        let card = cv.parse_str("4111 1111 1111 1111").unwrap();
+
+        assert_eq!("4111111111111111", card.get_number());
+        assert_eq!(CreditCardBrand::Visa, card.get_brand());
+    }
+
+    #[test]
+    fn test_credit_card_mastercard() {
+        let cv = allow_all();
+
+        let card = cv.parse_str("5500-0000-0000-0004").unwrap();
+
+        assert_eq!(CreditCardBrand::MasterCard, card.get_brand());
+    }
+
+    #[test]
+    fn test_credit_card_american_express() {
+        let cv = allow_all();
+
+        let card = cv.parse_str("340000000000009").unwrap();
+
+        assert_eq!(CreditCardBrand::AmericanExpress, card.get_brand());
+    }
+
+    #[test]
+    fn test_credit_card_discover() {
+        let cv = allow_all();
+
+        let card = cv.parse_str("6011000000000004").unwrap();
+
+        assert_eq!(CreditCardBrand::Discover, card.get_brand());
+    }
+
+    #[test]
+    fn test_credit_card_incorrect_checksum() {
+        let cv = allow_all();
+
+        cv.parse_str("4111111111111112").unwrap_err();
+    }
+
+    #[test]
+    fn test_credit_card_brand_not_allow() {
+        let cv = CreditCardValidator {
+            visa: ValidatorOption::NotAllow,
+            ..allow_all()
+        };
+
+        assert_eq!(CreditCardError::BrandNotAllow, cv.parse_str("4111111111111111").unwrap_err());
+    }
+}
+
+// Wrappers restricting a `CreditCard` to a single brand at the type level.
+
+macro_rules! credit_card_brand_wrapper {
+    ( $name:ident, $brand:path ) => {
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name(CreditCard);
+
+        impl From<$name> for CreditCard {
+            #[inline]
+            fn from(wrapper: $name) -> Self {
+                wrapper.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.0.number
+            }
+        }
+
+        impl Validated for $name {}
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ValidatedWrapper for $name {
+            type Error = CreditCardError;
+
+            #[inline]
+            fn from_string(number: String) -> Result<Self, Self::Error> {
+                $name::from_string(number)
+            }
+
+            #[inline]
+            fn from_str(number: &str) -> Result<Self, Self::Error> {
+                $name::from_str(number)
+            }
+        }
+
+        impl $name {
+            #[inline]
+            pub fn from_string(number: String) -> Result<Self, CreditCardError> {
+                let card = $name::create_validator().parse_string(number)?;
+
+                $name::from_credit_card(card)
+            }
+
+            #[inline]
+            #[allow(clippy::should_implement_trait)]
+            pub fn from_str(number: &str) -> Result<Self, CreditCardError> {
+                let card = $name::create_validator().parse_str(number)?;
+
+                $name::from_credit_card(card)
+            }
+
+            pub fn from_credit_card(card: CreditCard) -> Result<Self, CreditCardError> {
+                if card.brand != $brand {
+                    return Err(CreditCardError::BrandNotAllow);
+                }
+
+                Ok($name(card))
+            }
+
+            #[inline]
+            pub fn as_credit_card(&self) -> &CreditCard {
+                &self.0
+            }
+
+            #[inline]
+            pub fn into_credit_card(self) -> CreditCard {
+                self.0
+            }
+
+            fn create_validator() -> CreditCardValidator {
+                CreditCardValidator {
+                    visa: ValidatorOption::Allow,
+                    mastercard: ValidatorOption::Allow,
+                    american_express: ValidatorOption::Allow,
+                    diners_club: ValidatorOption::Allow,
+                    discover: ValidatorOption::Allow,
+                    jcb: ValidatorOption::Allow,
+                    union_pay: ValidatorOption::Allow,
+                    other: ValidatorOption::Allow,
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = CreditCardError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $name::from_str(s)
+            }
+        }
+    };
+}
+
+credit_card_brand_wrapper!(CreditCardVisaWithBrand, CreditCardBrand::Visa);
+credit_card_brand_wrapper!(CreditCardMastercardWithBrand, CreditCardBrand::MasterCard);
+credit_card_brand_wrapper!(CreditCardAmericanExpressWithBrand, CreditCardBrand::AmericanExpress);
+credit_card_brand_wrapper!(CreditCardDiscoverWithBrand, CreditCardBrand::Discover);
+
+impl ValidatedWrapper for CreditCard {
+    type Error = CreditCardError;
+
+    #[inline]
+    fn from_string(number: String) -> Result<Self, Self::Error> {
+        CreditCard::from_string(number)
+    }
+
+    #[inline]
+    fn from_str(number: &str) -> Result<Self, Self::Error> {
+        CreditCard::from_str(number)
+    }
+}
+
+impl CreditCard {
+    #[inline]
+    pub fn from_string(number: String) -> Result<Self, CreditCardError> {
+        CreditCard::create_validator().parse_string(number)
+    }
+
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(number: &str) -> Result<Self, CreditCardError> {
+        CreditCard::create_validator().parse_str(number)
+    }
+
+    #[inline]
+    fn create_validator() -> CreditCardValidator {
+        CreditCardValidator {
+            visa: ValidatorOption::Allow,
+            mastercard: ValidatorOption::Allow,
+            american_express: ValidatorOption::Allow,
+            diners_club: ValidatorOption::Allow,
+            discover: ValidatorOption::Allow,
+            jcb: ValidatorOption::Allow,
+            union_pay: ValidatorOption::Allow,
+            other: ValidatorOption::Allow,
+        }
+    }
+}
+
+impl FromStr for CreditCard {
+    type Err = CreditCardError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CreditCard::from_str(s)
+    }
+}
+
+#[cfg(feature = "rocketly")]
+impl<'a> ::rocket::request::FromFormValue<'a> for CreditCard {
+    type Error = CreditCardError;
+
+    #[inline]
+    fn from_form_value(form_value: &'a ::rocket::http::RawStr) -> Result<Self, Self::Error> {
+        CreditCard::from_string(form_value.url_decode().map_err(|_| CreditCardError::IncorrectFormat)?)
+    }
+}
+
+#[cfg(feature = "rocketly")]
+impl<'a> ::rocket::request::FromParam<'a> for CreditCard {
+    type Error = CreditCardError;
+
+    #[inline]
+    fn from_param(param: &'a ::rocket::http::RawStr) -> Result<Self, Self::Error> {
+        CreditCard::from_str(param)
+    }
+}
+
+#[cfg(feature = "serdely")]
+struct CreditCardStringVisitor;
+
+#[cfg(feature = "serdely")]
+impl<'de> ::serde::de::Visitor<'de> for CreditCardStringVisitor {
+    type Value = CreditCard;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a CreditCard string")
+    }
+
+    #[inline]
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        CreditCard::from_str(v).map_err(|err| E::custom(err.to_string()))
+    }
+
+    #[inline]
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        CreditCard::from_string(v).map_err(|err| E::custom(err.to_string()))
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl<'de> ::serde::Deserialize<'de> for CreditCard {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>, {
+        deserializer.deserialize_string(CreditCardStringVisitor)
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl ::serde::Serialize for CreditCard {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer, {
+        serializer.serialize_str(&self.number)
+    }
+}
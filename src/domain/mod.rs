@@ -3,6 +3,7 @@ extern crate regex;
 use self::regex::Regex;
 use super::{ValidatorOption, Validated, ValidatedWrapper};
 
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::{self, Display, Debug, Formatter};
 use std::str::Utf8Error;
@@ -23,6 +24,11 @@ pub enum DomainError {
     PortNotFound,
     LocalhostNotAllow,
     LocalhostNotFound,
+    FQDNNotAllow,
+    FQDNNotFound,
+    InvalidLabel { index: usize },
+    UserInfoNotAllow,
+    UserInfoNotFound,
     UTF8Error(Utf8Error),
 }
 
@@ -34,12 +40,33 @@ impl Display for DomainError {
 
 impl Error for DomainError {}
 
+/// Errors from `Domain::to_wire`/`Domain::to_wire_compressed`, the RFC 1035 label-sequence
+/// wire-format encoders.
+#[derive(Debug, PartialEq, Clone)]
+pub enum DomainWireError {
+    LabelTooLong,
+    NameTooLong,
+    OffsetTooLarge,
+}
+
+impl Display for DomainWireError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for DomainWireError {}
+
 pub type DomainResult = Result<Domain, DomainError>;
 
 #[derive(Debug, PartialEq)]
 pub struct DomainValidator {
     pub port: ValidatorOption,
     pub localhost: ValidatorOption,
+    pub fqdn: ValidatorOption,
+    pub strict: ValidatorOption,
+    pub escaped: ValidatorOption,
+    pub user_info: ValidatorOption,
 }
 
 #[derive(Clone)]
@@ -48,9 +75,12 @@ pub struct Domain {
     domain: usize,
     port: u16,
     port_index: usize,
+    user_info_index: usize,
     full_domain: String,
     full_domain_len: usize,
     is_localhost: bool,
+    is_fqdn: bool,
+    decoded_labels: Vec<Vec<u8>>,
 }
 
 impl Domain {
@@ -79,8 +109,8 @@ impl Domain {
     }
 
     pub fn get_sub_domain(&self) -> Option<&str> {
-        if self.domain > 0 {
-            Some(&self.full_domain[..(self.domain - 1)])
+        if self.domain > self.user_info_index {
+            Some(&self.full_domain[self.user_info_index..(self.domain - 1)])
         } else {
             None
         }
@@ -92,9 +122,9 @@ impl Domain {
 
     pub fn get_full_domain_without_port(&self) -> &str {
         if self.port_index != self.full_domain_len {
-            &self.full_domain[..(self.port_index - 1)]
+            &self.full_domain[self.user_info_index..(self.port_index - 1)]
         } else {
-            &self.full_domain
+            &self.full_domain[self.user_info_index..]
         }
     }
 
@@ -106,10 +136,119 @@ impl Domain {
         }
     }
 
+    /// The `user@` or `user:password@` portion of a `user@host:port`-style authority, excluding
+    /// the trailing `@`, when the `DomainValidator` that produced this `Domain` allowed it.
+    pub fn get_user_info(&self) -> Option<&str> {
+        if self.user_info_index > 0 {
+            Some(&self.full_domain[..(self.user_info_index - 1)])
+        } else {
+            None
+        }
+    }
+
     pub fn is_localhost(&self) -> bool {
         self.is_localhost
     }
 
+    /// `true` if the original input ended in a single trailing `.` (a fully-qualified domain
+    /// name), which is stripped from `full_domain` before storage.
+    pub fn is_fqdn(&self) -> bool {
+        self.is_fqdn
+    }
+
+    /// Yields each label of the domain (excluding the port, if any) from left to right, e.g.
+    /// `"www"`, `"tool"`, `"magiclen"`, `"org"` for `www.tool.magiclen.org`.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.get_full_domain_without_port().split('.')
+    }
+
+    /// Yields each label's decoded octets from left to right. Unlike `labels`, this undoes
+    /// zone-file escaping (`\.`, `\\`, `\DDD`) when the `DomainValidator` that produced this
+    /// `Domain` had `escaped` set to `ValidatorOption::Must`; otherwise it's equivalent to
+    /// `labels` with each label reinterpreted as raw bytes, except that the root/empty domain
+    /// yields no labels here (where `labels` yields a single empty one).
+    pub fn labels_decoded(&self) -> impl Iterator<Item = &[u8]> {
+        self.decoded_labels.iter().map(|label| label.as_slice())
+    }
+
+    /// Serializes this domain into RFC 1035 label-sequence wire format: each label as a single
+    /// length octet (1-63) followed by its bytes, terminated by the zero-length root label. A
+    /// root/empty `Domain` encodes as a single zero octet.
+    pub fn to_wire(&self) -> Result<Vec<u8>, DomainWireError> {
+        let mut out = Vec::new();
+
+        for label in self.labels_decoded() {
+            if label.is_empty() || label.len() > 63 {
+                return Err(DomainWireError::LabelTooLong);
+            }
+
+            out.push(label.len() as u8);
+            out.extend_from_slice(label);
+        }
+
+        out.push(0);
+
+        if out.len() > 255 {
+            return Err(DomainWireError::NameTooLong);
+        }
+
+        Ok(out)
+    }
+
+    /// Like `to_wire`, but compresses any label suffix already present in
+    /// `offsets` into a two-byte pointer (`0xC000 | offset`) instead of re-emitting it. `offsets`
+    /// maps a label suffix to the byte offset (within the wider message being built) at which it
+    /// was first written, and is updated in place with every new suffix this call writes.
+    /// `base_offset` is where this call's own output will land in that wider message.
+    pub fn to_wire_compressed(
+        &self,
+        offsets: &mut HashMap<Vec<Vec<u8>>, u16>,
+        base_offset: u16,
+    ) -> Result<Vec<u8>, DomainWireError> {
+        let labels: Vec<Vec<u8>> = self.labels_decoded().map(|label| label.to_vec()).collect();
+
+        let mut out = Vec::new();
+
+        for i in 0..labels.len() {
+            let suffix = &labels[i..];
+
+            if let Some(&offset) = offsets.get(suffix) {
+                out.extend_from_slice(&(0xC000u16 | offset).to_be_bytes());
+
+                if out.len() > 255 {
+                    return Err(DomainWireError::NameTooLong);
+                }
+
+                return Ok(out);
+            }
+
+            let current_offset = base_offset as usize + out.len();
+
+            if current_offset <= 0x3FFF {
+                offsets.insert(suffix.to_vec(), current_offset as u16);
+            } else {
+                return Err(DomainWireError::OffsetTooLarge);
+            }
+
+            let label = &labels[i];
+
+            if label.is_empty() || label.len() > 63 {
+                return Err(DomainWireError::LabelTooLong);
+            }
+
+            out.push(label.len() as u8);
+            out.extend_from_slice(label);
+        }
+
+        out.push(0);
+
+        if out.len() > 255 {
+            return Err(DomainWireError::NameTooLong);
+        }
+
+        Ok(out)
+    }
+
     pub fn into_string(self) -> String {
         self.full_domain
     }
@@ -166,7 +305,10 @@ impl DomainValidator {
     pub fn parse_string(&self, full_domain: String) -> DomainResult {
         let mut domain_inner = self.parse_inner(&full_domain)?;
 
-        domain_inner.full_domain = full_domain;
+        domain_inner.full_domain = match full_domain.strip_suffix('.') {
+            Some(stripped) => stripped.to_string(),
+            None => full_domain,
+        };
 
         Ok(domain_inner)
     }
@@ -174,13 +316,63 @@ impl DomainValidator {
     pub fn parse_str(&self, full_domain: &str) -> DomainResult {
         let mut domain_inner = self.parse_inner(full_domain)?;
 
-        domain_inner.full_domain.push_str(full_domain);
+        domain_inner.full_domain.push_str(full_domain.strip_suffix('.').unwrap_or(full_domain));
 
         Ok(domain_inner)
     }
 
     fn parse_inner(&self, full_domain: &str) -> DomainResult {
-        let c = match DOMAIN_RE.captures(&full_domain) {
+        // A single trailing `.` marks a fully-qualified domain name (e.g. `example.com.`); it's
+        // stripped before matching so the stored `full_domain` and its indices never include it.
+        // `.` alone is the DNS root.
+        let (full_domain, is_fqdn) = match full_domain.strip_suffix('.') {
+            Some(stripped) => (stripped, true),
+            None => (full_domain, false),
+        };
+
+        match self.fqdn {
+            ValidatorOption::Must if !is_fqdn => return Err(DomainError::FQDNNotFound),
+            ValidatorOption::NotAllow if is_fqdn => return Err(DomainError::FQDNNotAllow),
+            _ => ()
+        }
+
+        if full_domain.is_empty() {
+            return Ok(Domain {
+                top_level_domain: 0,
+                domain: 0,
+                port: 0,
+                port_index: 0,
+                user_info_index: 0,
+                full_domain: String::new(),
+                full_domain_len: 0,
+                is_localhost: false,
+                is_fqdn,
+                decoded_labels: Vec::new(),
+            });
+        }
+
+        if self.escaped.must() {
+            return self.parse_inner_escaped(full_domain, is_fqdn);
+        }
+
+        let (user_info_index, host_part) = match full_domain.rfind('@') {
+            Some(pos) => {
+                if self.user_info.not_allow() {
+                    return Err(DomainError::UserInfoNotAllow);
+                }
+
+                (pos + 1, &full_domain[(pos + 1)..])
+            }
+            None => {
+                if self.user_info.must() {
+                    return Err(DomainError::UserInfoNotFound);
+                }
+
+                (0, full_domain)
+            }
+        };
+
+        let c = match DOMAIN_RE.captures(host_part) {
             Some(c) => c,
             None => return Err(DomainError::IncorrectFormat)
         };
@@ -203,7 +395,7 @@ impl DomainValidator {
                     return Err(DomainError::IncorrectFormat);
                 }
 
-                m.start()
+                user_info_index + m.start()
             }
             None => {
                 unreachable!();
@@ -224,7 +416,7 @@ impl DomainValidator {
                     return Err(DomainError::IncorrectFormat);
                 }
 
-                m.start() + 1
+                user_info_index + m.start() + 1
             }
             None => {
                 if is_localhost {
@@ -253,9 +445,10 @@ impl DomainValidator {
                     return Err(DomainError::PortNotAllow);
                 }
 
-                let index = m.start() + 1;
+                let index = user_info_index + m.start() + 1;
+                let end = user_info_index + m.end();
 
-                port = match full_domain[index..m.end()].parse::<u16>() {
+                port = match full_domain[index..end].parse::<u16>() {
                     Ok(p) => p,
                     Err(_) => return Err(DomainError::IncorrectPort)
                 };
@@ -270,16 +463,247 @@ impl DomainValidator {
             }
         };
 
+        let full_domain_without_port = if port_index != full_domain_len {
+            &full_domain[user_info_index..(port_index - 1)]
+        } else {
+            &full_domain[user_info_index..]
+        };
+
+        if self.strict.must() {
+            for (index, label) in full_domain_without_port.split('.').enumerate() {
+                if !is_valid_ldh_label(label.as_bytes()) {
+                    return Err(DomainError::InvalidLabel { index });
+                }
+            }
+        }
+
+        let decoded_labels =
+            full_domain_without_port.split('.').map(|label| label.as_bytes().to_vec()).collect();
+
         Ok(Domain {
             top_level_domain,
             domain,
             port,
             port_index,
+            user_info_index,
             full_domain: String::new(),
             full_domain_len,
             is_localhost,
+            is_fqdn,
+            decoded_labels,
         })
     }
+
+    /// Parses `full_domain` (already stripped of any trailing FQDN `.`) character-by-character,
+    /// decoding zone-file escapes (`\.`, `\\`, `\DDD`) instead of relying on `DOMAIN_RE`, which
+    /// cannot tell an escaped dot from a label separator. Label/name length limits are enforced
+    /// against the *decoded* byte length, as DNS itself does.
+    fn parse_inner_escaped(&self, full_domain: &str, is_fqdn: bool) -> DomainResult {
+        // Userinfo splitting happens on the raw (undecoded) text, the same as in the
+        // `DOMAIN_RE`-based path, before zone-file escape decoding runs over the remaining host
+        // part. Unlike that path, it must be escape-aware: a label may legitimately contain a
+        // backslash-escaped `@` (e.g. `a\@b.org`), which should decode as part of the label
+        // rather than being mistaken for the userinfo delimiter.
+        let (user_info_index, host_part) = match find_unescaped_at(full_domain)? {
+            Some(pos) => {
+                if self.user_info.not_allow() {
+                    return Err(DomainError::UserInfoNotAllow);
+                }
+
+                (pos + 1, &full_domain[(pos + 1)..])
+            }
+            None => {
+                if self.user_info.must() {
+                    return Err(DomainError::UserInfoNotFound);
+                }
+
+                (0, full_domain)
+            }
+        };
+
+        let bytes = host_part.as_bytes();
+        let len = bytes.len();
+        let full_domain_len = user_info_index + len;
+
+        let mut labels: Vec<(usize, usize, Vec<u8>)> = Vec::new();
+        let mut label_start = 0usize;
+        let mut decoded = Vec::new();
+        let mut port = 0u16;
+        let mut port_index = full_domain_len;
+        let mut i = 0usize;
+
+        while i < len {
+            match bytes[i] {
+                b':' => {
+                    labels.push((label_start, i, decoded));
+                    decoded = Vec::new();
+
+                    let port_str = &host_part[(i + 1)..];
+
+                    if self.port.not_allow() {
+                        return Err(DomainError::PortNotAllow);
+                    }
+
+                    port = port_str.parse::<u16>().map_err(|_| DomainError::IncorrectPort)?;
+                    port_index = user_info_index + i + 1;
+                    i = len;
+                }
+                b'.' => {
+                    labels.push((label_start, i, decoded));
+                    decoded = Vec::new();
+                    label_start = i + 1;
+                    i += 1;
+                }
+                b'\\' => {
+                    if i + 1 >= len {
+                        return Err(DomainError::IncorrectFormat);
+                    }
+
+                    let next = bytes[i + 1];
+
+                    if next.is_ascii_digit() {
+                        if i + 4 > len
+                            || !bytes[i + 2].is_ascii_digit()
+                            || !bytes[i + 3].is_ascii_digit()
+                        {
+                            return Err(DomainError::IncorrectFormat);
+                        }
+
+                        let octet: u32 = host_part[(i + 1)..(i + 4)]
+                            .parse()
+                            .map_err(|_| DomainError::IncorrectFormat)?;
+
+                        if octet > 255 {
+                            return Err(DomainError::IncorrectFormat);
+                        }
+
+                        decoded.push(octet as u8);
+                        i += 4;
+                    } else {
+                        decoded.push(next);
+                        i += 2;
+                    }
+                }
+                b => {
+                    decoded.push(b);
+                    i += 1;
+                }
+            }
+        }
+
+        if port_index == full_domain_len {
+            labels.push((label_start, len, decoded));
+        }
+
+        if self.port.must() && port_index == full_domain_len {
+            return Err(DomainError::PortNotFound);
+        }
+
+        let decoded_total: usize = labels.iter().map(|(_, _, d)| d.len()).sum::<usize>()
+            + labels.len().saturating_sub(1);
+
+        if decoded_total > 255 {
+            return Err(DomainError::IncorrectFormat);
+        }
+
+        for (index, (_, _, label)) in labels.iter().enumerate() {
+            if label.is_empty() || label.len() > 63 {
+                return Err(DomainError::IncorrectFormat);
+            }
+
+            if self.strict.must() && !is_valid_ldh_label(label) {
+                return Err(DomainError::InvalidLabel { index });
+            }
+        }
+
+        let is_localhost = labels.len() == 1 && labels[0].2.eq_ignore_ascii_case(b"localhost");
+
+        if self.localhost.must() && !is_localhost {
+            return Err(DomainError::LocalhostNotFound);
+        }
+
+        if self.localhost.not_allow() && is_localhost {
+            return Err(DomainError::LocalhostNotAllow);
+        }
+
+        let (domain, top_level_domain) = if labels.len() >= 2 {
+            (
+                user_info_index + labels[labels.len() - 2].0,
+                user_info_index + labels[labels.len() - 1].0,
+            )
+        } else {
+            (user_info_index + labels[0].0, full_domain_len)
+        };
+
+        let decoded_labels = labels.into_iter().map(|(_, _, label)| label).collect();
+
+        Ok(Domain {
+            top_level_domain,
+            domain,
+            port,
+            port_index,
+            user_info_index,
+            full_domain: String::new(),
+            full_domain_len,
+            is_localhost,
+            is_fqdn,
+            decoded_labels,
+        })
+    }
+}
+
+/// Checks the RFC 1035 LDH rule: 1-63 octets, must begin and end with an ASCII alphanumeric
+/// character, and may otherwise contain ASCII letters, digits, and hyphens.
+fn is_valid_ldh_label(bytes: &[u8]) -> bool {
+    let len = bytes.len();
+
+    if len == 0 || len > 63 {
+        return false;
+    }
+
+    if !bytes[0].is_ascii_alphanumeric() || !bytes[len - 1].is_ascii_alphanumeric() {
+        return false;
+    }
+
+    bytes.iter().all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+}
+
+/// Finds the rightmost `@` in `s` that isn't itself a zone-file-escaped character, using the same
+/// escaping rules (`\.`, `\\`, `\DDD`) that `parse_inner_escaped` decodes with.
+fn find_unescaped_at(s: &str) -> Result<Option<usize>, DomainError> {
+    let bytes = s.as_bytes();
+    let len = bytes.len();
+
+    let mut last = None;
+    let mut i = 0usize;
+
+    while i < len {
+        match bytes[i] {
+            b'\\' => {
+                if i + 1 >= len {
+                    return Err(DomainError::IncorrectFormat);
+                }
+
+                if bytes[i + 1].is_ascii_digit() {
+                    if i + 4 > len || !bytes[i + 2].is_ascii_digit() || !bytes[i + 3].is_ascii_digit()
+                    {
+                        return Err(DomainError::IncorrectFormat);
+                    }
+
+                    i += 4;
+                } else {
+                    i += 2;
+                }
+            }
+            b'@' => {
+                last = Some(i);
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    Ok(last)
 }
 
 #[cfg(test)]
@@ -293,6 +717,10 @@ mod tests {
         let dv = DomainValidator {
             port: ValidatorOption::Allow,
             localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
         };
 
         let domain = dv.parse_string(domain).unwrap();
@@ -313,6 +741,10 @@ mod tests {
         let dv = DomainValidator {
             port: ValidatorOption::Allow,
             localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
         };
 
         let domain = dv.parse_string(domain).unwrap();
@@ -333,6 +765,10 @@ mod tests {
         let dv = DomainValidator {
             port: ValidatorOption::Allow,
             localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
         };
 
         let domain = dv.parse_string(domain).unwrap();
@@ -353,6 +789,10 @@ mod tests {
         let dv = DomainValidator {
             port: ValidatorOption::NotAllow,
             localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
         };
 
         dv.parse_string(domain).unwrap();
@@ -365,6 +805,10 @@ mod tests {
         let dv = DomainValidator {
             port: ValidatorOption::Allow,
             localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
         };
 
         dv.parse_string(domain).unwrap();
@@ -377,6 +821,10 @@ mod tests {
         let dv = DomainValidator {
             port: ValidatorOption::NotAllow,
             localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
         };
 
         dv.parse_string(domain).unwrap();
@@ -389,6 +837,10 @@ mod tests {
         let dv = DomainValidator {
             port: ValidatorOption::Allow,
             localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
         };
 
         dv.parse_string(domain).unwrap();
@@ -401,6 +853,10 @@ mod tests {
         let dv = DomainValidator {
             port: ValidatorOption::NotAllow,
             localhost: ValidatorOption::Allow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
         };
 
         dv.parse_string(domain).unwrap();
@@ -413,16 +869,418 @@ mod tests {
         let dv = DomainValidator {
             port: ValidatorOption::Allow,
             localhost: ValidatorOption::Allow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
         };
 
         dv.parse_string(domain).unwrap();
     }
+
+    #[test]
+    fn test_domain_fqdn_allow() {
+        let domain = "tool.magiclen.org.".to_string();
+
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_string(domain).unwrap();
+
+        assert_eq!("tool.magiclen.org", domain.get_full_domain());
+        assert_eq!(true, domain.is_fqdn());
+    }
+
+    #[test]
+    fn test_domain_fqdn_must() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Must,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        dv.parse_str("tool.magiclen.org.").unwrap();
+
+        assert_eq!(DomainError::FQDNNotFound, dv.parse_str("tool.magiclen.org").unwrap_err());
+    }
+
+    #[test]
+    fn test_domain_fqdn_not_allow() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::NotAllow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        dv.parse_str("tool.magiclen.org").unwrap();
+
+        assert_eq!(DomainError::FQDNNotAllow, dv.parse_str("tool.magiclen.org.").unwrap_err());
+    }
+
+    #[test]
+    fn test_domain_root() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Must,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str(".").unwrap();
+
+        assert_eq!("", domain.get_full_domain());
+        assert_eq!(true, domain.is_fqdn());
+    }
+
+    #[test]
+    fn test_domain_labels() {
+        let dv = DomainValidator {
+            port: ValidatorOption::Allow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str("www.tool.magiclen.org:8080").unwrap();
+
+        assert_eq!(
+            vec!["www", "tool", "magiclen", "org"],
+            domain.labels().collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn test_domain_strict_allow() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        dv.parse_str("123.magiclen.org").unwrap();
+        dv.parse_str("-magiclen.org").unwrap();
+    }
+
+    #[test]
+    fn test_domain_strict_must_valid() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Must,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        dv.parse_str("magiclen.org").unwrap();
+        dv.parse_str("123.magiclen.org").unwrap();
+        dv.parse_str("a-b.magiclen.org").unwrap();
+    }
+
+    #[test]
+    fn test_domain_strict_must_trailing_hyphen() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Must,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        assert_eq!(
+            DomainError::InvalidLabel { index: 0 },
+            dv.parse_str("magiclen-.org").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_domain_strict_must_leading_hyphen() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Must,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        assert_eq!(
+            DomainError::InvalidLabel { index: 0 },
+            dv.parse_str("-magiclen.org").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_domain_strict_must_underscore() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Must,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        // `_` passes the loose `DOMAIN_RE` but is not an ASCII alphanumeric or `-`.
+        assert_eq!(
+            DomainError::InvalidLabel { index: 0 },
+            dv.parse_str("_magiclen.org").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_domain_escaped_literal_dot() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Must,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str(r"magic\.len.org").unwrap();
+
+        assert_eq!(r"magic\.len.org", domain.get_full_domain());
+        assert_eq!(
+            vec![b"magic.len".to_vec(), b"org".to_vec()],
+            domain.labels_decoded().map(|l| l.to_vec()).collect::<Vec<Vec<u8>>>()
+        );
+    }
+
+    #[test]
+    fn test_domain_escaped_backslash_and_octet() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Must,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str(r"a\\b\065.org").unwrap();
+
+        let labels: Vec<Vec<u8>> = domain.labels_decoded().map(|l| l.to_vec()).collect();
+
+        assert_eq!(vec![b"a\\bA".to_vec(), b"org".to_vec()], labels);
+    }
+
+    #[test]
+    fn test_domain_escaped_with_port() {
+        let dv = DomainValidator {
+            port: ValidatorOption::Allow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Must,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str(r"magic\.len.org:8080").unwrap();
+
+        assert_eq!(8080, domain.get_port().unwrap());
+        assert_eq!(r"magic\.len.org", domain.get_full_domain_without_port());
+    }
+
+    #[test]
+    fn test_domain_escaped_invalid() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Must,
+            user_info: ValidatorOption::Allow,
+        };
+
+        dv.parse_str(r"magic\").unwrap_err();
+        dv.parse_str(r"magic\12.org").unwrap_err();
+        dv.parse_str(r"magic\999.org").unwrap_err();
+    }
+
+    #[test]
+    fn test_domain_escaped_with_user_info() {
+        let dv = DomainValidator {
+            port: ValidatorOption::Allow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Must,
+            user_info: ValidatorOption::Must,
+        };
+
+        let domain = dv.parse_str(r"user@magic\.len.org:8080").unwrap();
+
+        assert_eq!("user", domain.get_user_info().unwrap());
+        assert_eq!(8080, domain.get_port().unwrap());
+        assert_eq!(r"magic\.len.org", domain.get_full_domain_without_port());
+        assert_eq!(
+            vec![b"magic.len".to_vec(), b"org".to_vec()],
+            domain.labels_decoded().map(|l| l.to_vec()).collect::<Vec<Vec<u8>>>()
+        );
+    }
+
+    #[test]
+    fn test_domain_escaped_label_with_escaped_at() {
+        let dv = DomainValidator {
+            port: ValidatorOption::Allow,
+            localhost: ValidatorOption::NotAllow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::NotAllow,
+            escaped: ValidatorOption::Must,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str(r"a\@b.org").unwrap();
+
+        assert!(domain.get_user_info().is_none());
+        assert_eq!(
+            vec![b"a@b".to_vec(), b"org".to_vec()],
+            domain.labels_decoded().map(|l| l.to_vec()).collect::<Vec<Vec<u8>>>()
+        );
+    }
+
+    #[test]
+    fn test_domain_user_info() {
+        let dv = DomainValidator {
+            port: ValidatorOption::Allow,
+            localhost: ValidatorOption::Allow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str("user:pass@tool.magiclen.org:8080").unwrap();
+
+        assert_eq!("user:pass", domain.get_user_info().unwrap());
+        assert_eq!("tool.magiclen.org", domain.get_full_domain_without_port());
+        assert_eq!(8080, domain.get_port().unwrap());
+    }
+
+    #[test]
+    fn test_domain_user_info_not_allow() {
+        let dv = DomainValidator {
+            port: ValidatorOption::Allow,
+            localhost: ValidatorOption::Allow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::NotAllow,
+        };
+
+        dv.parse_str("user@tool.magiclen.org").unwrap_err();
+    }
+
+    #[test]
+    fn test_domain_user_info_must() {
+        let dv = DomainValidator {
+            port: ValidatorOption::Allow,
+            localhost: ValidatorOption::Allow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Must,
+        };
+
+        dv.parse_str("tool.magiclen.org").unwrap_err();
+
+        let domain = dv.parse_str("user@tool.magiclen.org").unwrap();
+
+        assert_eq!("user", domain.get_user_info().unwrap());
+        assert_eq!("tool", domain.get_sub_domain().unwrap());
+        assert_eq!("magiclen", domain.get_domain());
+        assert_eq!("org", domain.get_top_level_domain().unwrap());
+    }
+
+    #[test]
+    fn test_domain_to_wire() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::Allow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str("tool.magiclen.org").unwrap();
+
+        assert_eq!(
+            vec![4, b't', b'o', b'o', b'l', 8, b'm', b'a', b'g', b'i', b'c', b'l', b'e', b'n', 3, b'o', b'r', b'g', 0],
+            domain.to_wire().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_domain_to_wire_root() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::Allow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str("").unwrap();
+
+        assert_eq!(vec![0], domain.to_wire().unwrap());
+    }
+
+    #[test]
+    fn test_domain_to_wire_compressed() {
+        let dv = DomainValidator {
+            port: ValidatorOption::NotAllow,
+            localhost: ValidatorOption::Allow,
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let a = dv.parse_str("tool.magiclen.org").unwrap();
+        let b = dv.parse_str("www.magiclen.org").unwrap();
+
+        let mut offsets = HashMap::new();
+
+        let wire_a = a.to_wire_compressed(&mut offsets, 0).unwrap();
+
+        assert_eq!(wire_a, a.to_wire().unwrap());
+
+        let wire_b = b.to_wire_compressed(&mut offsets, wire_a.len() as u16).unwrap();
+
+        // "magiclen.org" was already written as part of `a`, so `b` should just point to it.
+        let pointer = 0xC000u16 | (1 + 4); // past the single length-prefixed "tool" label of `a`
+        assert_eq!(vec![3, b'w', b'w', b'w', (pointer >> 8) as u8, (pointer & 0xFF) as u8], wire_b);
+    }
 }
 
 // TODO ----------
 
 macro_rules! extend {
-    ( $name:ident, $port:expr, $localhost:expr ) => {
+    ( $name:ident, $port:expr, $localhost:expr, $fqdn:expr, $strict:expr, $escaped:expr, $user_info:expr ) => {
         #[derive(Clone, PartialEq, Eq, Hash)]
         pub struct $name(Domain);
 
@@ -503,10 +1361,59 @@ macro_rules! extend {
                     }
                     _=>()
                 }
+                match $fqdn {
+                    ValidatorOption::Must => {
+                        if !domain.is_fqdn {
+                            return Err(DomainError::FQDNNotFound)
+                        }
+                    },
+                    ValidatorOption::NotAllow => {
+                        if domain.is_fqdn {
+                            return Err(DomainError::FQDNNotAllow)
+                        }
+                    }
+                    _=>()
+                }
+
+                match $user_info {
+                    ValidatorOption::Must => {
+                        if domain.user_info_index == 0 {
+                            return Err(DomainError::UserInfoNotFound)
+                        }
+                    },
+                    ValidatorOption::NotAllow => {
+                        if domain.user_info_index != 0 {
+                            return Err(DomainError::UserInfoNotAllow)
+                        }
+                    }
+                    _=>()
+                }
+
+                if $strict.must() {
+                    for (index, label) in domain.labels().enumerate() {
+                        if !is_valid_ldh_label(label.as_bytes()) {
+                            return Err(DomainError::InvalidLabel { index })
+                        }
+                    }
+                }
 
                 Ok($name(domain))
             }
 
+            /// Yields each label of the domain (excluding the port, if any) from left to right.
+            pub fn labels(&self) -> impl Iterator<Item = &str> {
+                self.0.labels()
+            }
+
+            /// Yields each label's decoded octets from left to right.
+            pub fn labels_decoded(&self) -> impl Iterator<Item = &[u8]> {
+                self.0.labels_decoded()
+            }
+
+            pub fn get_user_info(&self) -> Option<&str> {
+                self.0.get_user_info()
+            }
+
             pub fn into_domain(self) -> Domain {
                 self.0
             }
@@ -519,6 +1426,10 @@ macro_rules! extend {
                 DomainValidator {
                     port: $port,
                     localhost: $localhost,
+                    fqdn: $fqdn,
+                    strict: $strict,
+                    escaped: $escaped,
+                    user_info: $user_info,
                 }
             }
         }
@@ -539,6 +1450,10 @@ macro_rules! extend {
             pub fn get_full_domain(&self) -> &str {
                 self.0.get_full_domain()
             }
+
+            pub fn is_fqdn(&self) -> bool {
+                self.0.is_fqdn()
+            }
         }
 
         #[cfg(feature = "rocketly")]
@@ -597,7 +1512,7 @@ macro_rules! extend {
     };
 }
 
-extend!(DomainLocalhostableWithPort, ValidatorOption::Must, ValidatorOption::Allow);
+extend!(DomainLocalhostableWithPort, ValidatorOption::Must, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow);
 
 impl DomainLocalhostableWithPort {
     pub fn get_full_domain_without_port(&self) -> &str {
@@ -613,7 +1528,7 @@ impl DomainLocalhostableWithPort {
     }
 }
 
-extend!(DomainLocalhostableAllowPort, ValidatorOption::Allow, ValidatorOption::Allow);
+extend!(DomainLocalhostableAllowPort, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow);
 
 impl DomainLocalhostableAllowPort {
     pub fn get_full_domain_without_port(&self) -> &str {
@@ -629,7 +1544,7 @@ impl DomainLocalhostableAllowPort {
     }
 }
 
-extend!(DomainLocalhostableWithoutPort, ValidatorOption::NotAllow, ValidatorOption::Allow);
+extend!(DomainLocalhostableWithoutPort, ValidatorOption::NotAllow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow);
 
 impl DomainLocalhostableWithoutPort {
     pub fn is_localhost(&self) -> bool {
@@ -637,7 +1552,7 @@ impl DomainLocalhostableWithoutPort {
     }
 }
 
-extend!(DomainUnlocalhostableWithPort, ValidatorOption::Must, ValidatorOption::NotAllow);
+extend!(DomainUnlocalhostableWithPort, ValidatorOption::Must, ValidatorOption::NotAllow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow);
 
 impl DomainUnlocalhostableWithPort {
     pub fn get_full_domain_without_port(&self) -> &str {
@@ -649,7 +1564,7 @@ impl DomainUnlocalhostableWithPort {
     }
 }
 
-extend!(DomainUnlocalhostableAllowPort, ValidatorOption::Allow, ValidatorOption::NotAllow);
+extend!(DomainUnlocalhostableAllowPort, ValidatorOption::Allow, ValidatorOption::NotAllow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow);
 
 impl DomainUnlocalhostableAllowPort {
     pub fn get_full_domain_without_port(&self) -> &str {
@@ -661,6 +1576,18 @@ impl DomainUnlocalhostableAllowPort {
     }
 }
 
-extend!(DomainUnlocalhostableWithoutPort, ValidatorOption::NotAllow, ValidatorOption::NotAllow);
+extend!(DomainUnlocalhostableWithoutPort, ValidatorOption::NotAllow, ValidatorOption::NotAllow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow);
+
+impl DomainUnlocalhostableWithoutPort {}
+
+extend!(DomainWithUserInfo, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Allow, ValidatorOption::Must);
+
+impl DomainWithUserInfo {
+    pub fn get_full_domain_without_port(&self) -> &str {
+        self.0.get_full_domain_without_port()
+    }
 
-impl DomainUnlocalhostableWithoutPort {}
\ No newline at end of file
+    pub fn get_port(&self) -> Option<u16> {
+        self.0.get_port()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,461 @@
+use super::domain::{Domain, DomainError, DomainValidator};
+use super::ipv4::{IPv4, IPv4Error, IPv4Validator};
+use super::{Validated, ValidatedWrapper, ValidatorOption};
+
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::net::Ipv6Addr;
+use std::ops::Deref;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum HostError {
+    IncorrectFormat,
+    Domain(DomainError),
+    IPv4(IPv4Error),
+}
+
+impl Display for HostError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for HostError {}
+
+impl From<DomainError> for HostError {
+    #[inline]
+    fn from(err: DomainError) -> Self {
+        HostError::Domain(err)
+    }
+}
+
+impl From<IPv4Error> for HostError {
+    #[inline]
+    fn from(err: IPv4Error) -> Self {
+        HostError::IPv4(err)
+    }
+}
+
+pub type HostResult = Result<Host, HostError>;
+
+// `ValidatorOption` doesn't implement `Clone`, so this copies its value by hand when a
+// `HostValidator`'s option needs to be forwarded to a nested `IPv4Validator`/`DomainValidator`.
+fn dup_option(option: &ValidatorOption) -> ValidatorOption {
+    match option {
+        ValidatorOption::Must => ValidatorOption::Must,
+        ValidatorOption::Allow => ValidatorOption::Allow,
+        ValidatorOption::NotAllow => ValidatorOption::NotAllow,
+    }
+}
+
+/// Validates an `authority`-style host, which may be a domain name, an IPv4 address, or a
+/// bracketed IPv6 address, each with an optional port.
+#[derive(Debug, PartialEq)]
+pub struct HostValidator {
+    pub port: ValidatorOption,
+    pub local: ValidatorOption,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum HostInner {
+    Domain(Domain),
+    IPv4(IPv4),
+    IPv6 {
+        address: Ipv6Addr,
+        port: Option<u16>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Host {
+    inner: HostInner,
+    full_host: String,
+}
+
+impl Host {
+    pub fn get_full_host(&self) -> &str {
+        &self.full_host
+    }
+
+    pub fn get_port(&self) -> Option<u16> {
+        match &self.inner {
+            HostInner::Domain(domain) => domain.get_port(),
+            HostInner::IPv4(ipv4) => ipv4.get_port(),
+            HostInner::IPv6 {
+                port, ..
+            } => *port,
+        }
+    }
+
+    pub fn is_local(&self) -> bool {
+        match &self.inner {
+            HostInner::Domain(domain) => domain.is_localhost(),
+            HostInner::IPv4(ipv4) => ipv4.is_local(),
+            HostInner::IPv6 {
+                address, ..
+            } => address.is_loopback(),
+        }
+    }
+
+    pub fn get_domain(&self) -> Option<&Domain> {
+        match &self.inner {
+            HostInner::Domain(domain) => Some(domain),
+            _ => None,
+        }
+    }
+
+    pub fn get_ipv4(&self) -> Option<&IPv4> {
+        match &self.inner {
+            HostInner::IPv4(ipv4) => Some(ipv4),
+            _ => None,
+        }
+    }
+
+    pub fn get_ipv6(&self) -> Option<&Ipv6Addr> {
+        match &self.inner {
+            HostInner::IPv6 {
+                address, ..
+            } => Some(address),
+            _ => None,
+        }
+    }
+
+    pub fn into_string(self) -> String {
+        self.full_host
+    }
+}
+
+impl Deref for Host {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.full_host
+    }
+}
+
+impl Validated for Host {}
+
+impl Display for Host {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.full_host)?;
+        Ok(())
+    }
+}
+
+impl HostValidator {
+    pub fn is_host(&self, full_host: &str) -> bool {
+        self.parse_inner(full_host).is_ok()
+    }
+
+    pub fn parse_string(&self, full_host: String) -> HostResult {
+        let mut host_inner = self.parse_inner(&full_host)?;
+
+        host_inner.full_host = full_host;
+
+        Ok(host_inner)
+    }
+
+    pub fn parse_str(&self, full_host: &str) -> HostResult {
+        let mut host_inner = self.parse_inner(full_host)?;
+
+        host_inner.full_host.push_str(full_host);
+
+        Ok(host_inner)
+    }
+
+    fn parse_inner(&self, full_host: &str) -> HostResult {
+        if full_host.starts_with('[') {
+            return self.parse_bracketed_ipv6(full_host);
+        }
+
+        if looks_like_ipv4(full_host) {
+            let iv = IPv4Validator {
+                port: dup_option(&self.port),
+                local: dup_option(&self.local),
+                ipv6: ValidatorOption::NotAllow,
+                blocks: None,
+            };
+
+            let ipv4 = iv.parse_str(full_host)?;
+
+            return Ok(Host {
+                inner: HostInner::IPv4(ipv4),
+                full_host: String::new(),
+            });
+        }
+
+        // A bare (unbracketed) IPv6 address can't carry a `:port` suffix of its own, since a
+        // trailing `:1234` is indistinguishable from more address groups; only try this when the
+        // whole string parses as an address with nothing left over.
+        if let Ok(address) = Ipv6Addr::from_str(full_host) {
+            return self.build_ipv6_host(address, None);
+        }
+
+        let dv = DomainValidator {
+            port: dup_option(&self.port),
+            localhost: dup_option(&self.local),
+            fqdn: ValidatorOption::Allow,
+            strict: ValidatorOption::Allow,
+            escaped: ValidatorOption::Allow,
+            user_info: ValidatorOption::Allow,
+        };
+
+        let domain = dv.parse_str(full_host)?;
+
+        Ok(Host {
+            inner: HostInner::Domain(domain),
+            full_host: String::new(),
+        })
+    }
+
+    fn parse_bracketed_ipv6(&self, full_host: &str) -> HostResult {
+        let close = match full_host.find(']') {
+            Some(index) => index,
+            None => return Err(HostError::IncorrectFormat),
+        };
+
+        let address = Ipv6Addr::from_str(&full_host[1..close])
+            .map_err(|_| HostError::IncorrectFormat)?;
+
+        let rest = &full_host[(close + 1)..];
+
+        let port = if rest.is_empty() {
+            None
+        } else {
+            let port_str = rest.strip_prefix(':').ok_or(HostError::IncorrectFormat)?;
+
+            if self.port.not_allow() {
+                return Err(HostError::IncorrectFormat);
+            }
+
+            Some(port_str.parse::<u16>().map_err(|_| HostError::IncorrectFormat)?)
+        };
+
+        self.build_ipv6_host(address, port)
+    }
+
+    fn build_ipv6_host(&self, address: Ipv6Addr, port: Option<u16>) -> HostResult {
+        if port.is_none() && self.port.must() {
+            return Err(HostError::IncorrectFormat);
+        }
+
+        if self.local.must() && !address.is_loopback() {
+            return Err(HostError::IncorrectFormat);
+        }
+
+        if self.local.not_allow() && address.is_loopback() {
+            return Err(HostError::IncorrectFormat);
+        }
+
+        Ok(Host {
+            inner: HostInner::IPv6 {
+                address,
+                port,
+            },
+            full_host: String::new(),
+        })
+    }
+}
+
+/// Whether `s` looks enough like a dotted-decimal IPv4 address (vs. a domain name) to be routed
+/// to `IPv4Validator` instead of `DomainValidator`.
+fn looks_like_ipv4(s: &str) -> bool {
+    let host_part = match s.find(':') {
+        Some(index) => &s[..index],
+        None => s,
+    };
+
+    !host_part.is_empty()
+        && host_part.split('.').count() == 4
+        && host_part.split('.').all(|octet| !octet.is_empty() && octet.bytes().all(|b| b.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_domain() {
+        let hv = HostValidator {
+            port: ValidatorOption::Allow,
+            local: ValidatorOption::Allow,
+        };
+
+        let host = hv.parse_str("example.com:8080").unwrap();
+
+        assert_eq!("example.com:8080", host.get_full_host());
+        assert_eq!(8080, host.get_port().unwrap());
+        assert!(host.get_domain().is_some());
+    }
+
+    #[test]
+    fn test_host_ipv4() {
+        let hv = HostValidator {
+            port: ValidatorOption::Allow,
+            local: ValidatorOption::Allow,
+        };
+
+        let host = hv.parse_str("127.0.0.1:3000").unwrap();
+
+        assert_eq!(3000, host.get_port().unwrap());
+        assert!(host.get_ipv4().is_some());
+        assert!(host.is_local());
+    }
+
+    #[test]
+    fn test_host_ipv6() {
+        let hv = HostValidator {
+            port: ValidatorOption::Allow,
+            local: ValidatorOption::Allow,
+        };
+
+        let host = hv.parse_str("[::1]:9000").unwrap();
+
+        assert_eq!(9000, host.get_port().unwrap());
+        assert_eq!(&Ipv6Addr::LOCALHOST, host.get_ipv6().unwrap());
+        assert!(host.is_local());
+    }
+
+    #[test]
+    fn test_host_bare_ipv6() {
+        let hv = HostValidator {
+            port: ValidatorOption::Allow,
+            local: ValidatorOption::Allow,
+        };
+
+        let host = hv.parse_str("2001:db8::1").unwrap();
+
+        assert_eq!(None, host.get_port());
+        assert_eq!(&Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), host.get_ipv6().unwrap());
+        assert!(!host.is_local());
+
+        let host = hv.parse_str("::1").unwrap();
+
+        assert_eq!(&Ipv6Addr::LOCALHOST, host.get_ipv6().unwrap());
+        assert!(host.is_local());
+    }
+
+    #[test]
+    fn test_host_incorrect_format() {
+        let hv = HostValidator {
+            port: ValidatorOption::Allow,
+            local: ValidatorOption::Allow,
+        };
+
+        hv.parse_str("[::1").unwrap_err();
+    }
+}
+
+// Host's wrapper struct is itself
+impl ValidatedWrapper for Host {
+    type Error = HostError;
+
+    #[inline]
+    fn from_string(full_host: String) -> Result<Self, Self::Error> {
+        Host::from_string(full_host)
+    }
+
+    #[inline]
+    fn from_str(full_host: &str) -> Result<Self, Self::Error> {
+        Host::from_str(full_host)
+    }
+}
+
+impl Host {
+    #[inline]
+    pub fn from_string(full_host: String) -> Result<Self, HostError> {
+        Host::create_validator().parse_string(full_host)
+    }
+
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(full_host: &str) -> Result<Self, HostError> {
+        Host::create_validator().parse_str(full_host)
+    }
+
+    #[inline]
+    fn create_validator() -> HostValidator {
+        HostValidator {
+            port: ValidatorOption::Allow,
+            local: ValidatorOption::Allow,
+        }
+    }
+}
+
+impl FromStr for Host {
+    type Err = HostError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Host::from_str(s)
+    }
+}
+
+#[cfg(feature = "rocketly")]
+impl<'a> ::rocket::request::FromFormValue<'a> for Host {
+    type Error = HostError;
+
+    #[inline]
+    fn from_form_value(form_value: &'a ::rocket::http::RawStr) -> Result<Self, Self::Error> {
+        Host::from_string(form_value.url_decode().map_err(|_| HostError::IncorrectFormat)?)
+    }
+}
+
+#[cfg(feature = "rocketly")]
+impl<'a> ::rocket::request::FromParam<'a> for Host {
+    type Error = HostError;
+
+    #[inline]
+    fn from_param(param: &'a ::rocket::http::RawStr) -> Result<Self, Self::Error> {
+        Host::from_str(param)
+    }
+}
+
+#[cfg(feature = "serdely")]
+struct HostStringVisitor;
+
+#[cfg(feature = "serdely")]
+impl<'de> ::serde::de::Visitor<'de> for HostStringVisitor {
+    type Value = Host;
+
+    #[inline]
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Host string")
+    }
+
+    #[inline]
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        Host::from_str(v).map_err(|err| E::custom(err.to_string()))
+    }
+
+    #[inline]
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        Host::from_string(v).map_err(|err| E::custom(err.to_string()))
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl<'de> ::serde::Deserialize<'de> for Host {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>, {
+        deserializer.deserialize_string(HostStringVisitor)
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl ::serde::Serialize for Host {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer, {
+        serializer.serialize_str(&self.full_host)
+    }
+}
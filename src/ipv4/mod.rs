@@ -1,19 +1,75 @@
-extern crate regex;
-
-use self::regex::Regex;
 use super::{ValidatorOption, Validated, ValidatedWrapper};
 
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt::{self, Display, Debug, Formatter};
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::iter::FromIterator;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, ToSocketAddrs};
 use std::str::{Utf8Error, FromStr};
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
-lazy_static! {
-    pub(crate) static ref IPV4_RE: Regex = {
-        Regex::new(r"^((25[0-5]|2[0-4][0-9]|1[0-9]{1,2}|[1-9]?[0-9])\.(25[0-5]|2[0-4][0-9]|1[0-9]{1,2}|[1-9]?[0-9])\.(25[0-5]|2[0-4][0-9]|1[0-9]{1,2}|[1-9]?[0-9])\.(25[0-5]|2[0-4][0-9]|1[0-9]{1,2}|[1-9]?[0-9]))(:(\d{1,5}))?$").unwrap()
-    };
+/// Parses a single 1-3 digit decimal octet (no leading zeros) starting at `start`, returning the
+/// value and the index right after it.
+fn parse_octet(bytes: &[u8], start: usize) -> Option<(u8, usize)> {
+    let mut i = start;
+    let mut value: u32 = 0;
+    let mut digits = 0u32;
+
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        if digits == 3 {
+            return None;
+        }
+
+        value = value * 10 + u32::from(bytes[i] - b'0');
+        digits += 1;
+        i += 1;
+    }
+
+    if digits == 0 || value > 255 {
+        return None;
+    }
+
+    if digits > 1 && bytes[start] == b'0' {
+        return None;
+    }
+
+    Some((value as u8, i))
+}
+
+/// Parses a dotted-decimal IPv4 address with an optional `:port` suffix directly from bytes.
+///
+/// This replaces the previous backtracking regex with a single linear scan; returns the parsed
+/// address and, if present, the byte index at which the port digits start.
+fn parse_ipv4_and_port(ipv4: &str) -> Option<(Ipv4Addr, Option<usize>)> {
+    let bytes = ipv4.as_bytes();
+
+    let mut octets = [0u8; 4];
+    let mut i = 0;
+
+    for (octet_index, octet) in octets.iter_mut().enumerate() {
+        let (value, next) = parse_octet(bytes, i)?;
+
+        *octet = value;
+        i = next;
+
+        if octet_index < 3 {
+            if bytes.get(i) != Some(&b'.') {
+                return None;
+            }
+
+            i += 1;
+        }
+    }
+
+    let address = Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]);
+
+    match bytes.get(i) {
+        None => Some((address, None)),
+        Some(b':') => Some((address, Some(i + 1))),
+        Some(_) => None,
+    }
 }
 
 fn is_local_ipv4(addr: &Ipv4Addr) -> bool {
@@ -30,6 +86,7 @@ pub enum IPv4Error {
     LocalNotFound,
     IPv6NotAllow,
     IPv6NotFound,
+    BlocksNotAllow,
     UTF8Error(Utf8Error),
 }
 
@@ -48,10 +105,19 @@ pub struct IPv4Validator {
     pub port: ValidatorOption,
     pub local: ValidatorOption,
     pub ipv6: ValidatorOption,
+    /// Restricts which addresses are accepted beyond the coarse `local` flag: `Some(Allow(set))`
+    /// requires the address to fall inside `set`, `Some(Deny(set))` requires it to fall outside,
+    /// `None` applies no restriction.
+    pub blocks: Option<IPv4BlocksRule>,
 }
 
 pub type IPv4Port = u16;
 
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 #[derive(Clone)]
 pub struct IPv4 {
     ip: Ipv4Addr,
@@ -94,6 +160,55 @@ impl IPv4 {
     pub fn into_string(self) -> String {
         self.full_ipv4
     }
+
+    /// Method form of `From<&IPv4> for IpAddr`.
+    pub fn to_ip_addr(&self) -> IpAddr {
+        IpAddr::V4(self.ip)
+    }
+
+    /// `None` if the `IPv4` was parsed without a port.
+    pub fn to_socket_addr(&self) -> Option<SocketAddrV4> {
+        self.get_port().map(|port| SocketAddrV4::new(self.ip, port))
+    }
+}
+
+impl From<&IPv4> for IpAddr {
+    #[inline]
+    fn from(ipv4: &IPv4) -> Self {
+        IpAddr::V4(ipv4.ip)
+    }
+}
+
+impl From<IPv4> for IpAddr {
+    #[inline]
+    fn from(ipv4: IPv4) -> Self {
+        IpAddr::V4(ipv4.ip)
+    }
+}
+
+impl TryFrom<&IPv4> for SocketAddrV4 {
+    type Error = IPv4Error;
+
+    /// Fails with `IPv4Error::PortNotFound` if the `IPv4` was parsed without a port.
+    fn try_from(ipv4: &IPv4) -> Result<Self, Self::Error> {
+        match ipv4.get_port() {
+            Some(port) => Ok(SocketAddrV4::new(ipv4.ip, port)),
+            None => Err(IPv4Error::PortNotFound),
+        }
+    }
+}
+
+impl ToSocketAddrs for IPv4 {
+    type Iter = std::option::IntoIter<SocketAddr>;
+
+    /// Lets a validated `IPv4` (with a port) be handed straight to `TcpStream::connect`. Fails
+    /// with `InvalidInput` if the `IPv4` was parsed without a port.
+    fn to_socket_addrs(&self) -> std::io::Result<Self::Iter> {
+        match self.to_socket_addr() {
+            Some(addr) => Ok(Some(SocketAddr::V4(addr)).into_iter()),
+            None => Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "the IPv4 has no port")),
+        }
+    }
 }
 
 impl Deref for IPv4 {
@@ -197,21 +312,33 @@ impl IPv4Validator {
         let mut port_index = 0;
         let mut full_ipv4_len = 0usize;
 
-        let ip = match IPV4_RE.captures(&ipv4) {
-            Some(c) => {
+        let ip = match parse_ipv4_and_port(ipv4) {
+            Some((address, port_part)) => {
                 if self.ipv6.must() {
                     return Err(IPv4Error::IPv6NotFound);
                 }
 
-                match c.get(7) {
-                    Some(m) => {
+                match port_part {
+                    Some(start) => {
                         if self.port.not_allow() {
                             return Err(IPv4Error::PortNotAllow);
                         }
 
-                        port = match ipv4[m.start()..m.end()].parse::<u16>() {
+                        let port_str = &ipv4[start..];
+
+                        // Matches the old `IPV4_RE`'s `\d{1,5}` cap: reject an empty, overlong,
+                        // or non-digit port rather than letting `u16::parse` accept arbitrarily
+                        // many leading-zero digits (e.g. "0000000001").
+                        if port_str.is_empty()
+                            || port_str.len() > 5
+                            || !port_str.bytes().all(|b| b.is_ascii_digit())
+                        {
+                            return Err(IPv4Error::IncorrectPort);
+                        }
+
+                        port = match port_str.parse::<u16>() {
                             Ok(p) => {
-                                port_index = m.start();
+                                port_index = start;
                                 p
                             }
                             Err(_) => return Err(IPv4Error::IncorrectPort)
@@ -225,15 +352,9 @@ impl IPv4Validator {
                     }
                 };
 
-                match c.get(1) {
-                    Some(m) => {
-                        full_ipv4_len = 1;
-                        Ipv4Addr::from_str(&ipv4[m.start()..m.end()]).map_err(|_| IPv4Error::IncorrectFormat)?
-                    }
-                    None => {
-                        unreachable!();
-                    }
-                }
+                full_ipv4_len = ipv4.len();
+
+                address
             }
             None => {
                 if ipv4.starts_with("[") {
@@ -327,6 +448,20 @@ impl IPv4Validator {
             _ => ()
         }
 
+        match &self.blocks {
+            Some(IPv4BlocksRule::Allow(set)) => {
+                if !set.contains(&ip) {
+                    return Err(IPv4Error::BlocksNotAllow);
+                }
+            }
+            Some(IPv4BlocksRule::Deny(set)) => {
+                if set.contains(&ip) {
+                    return Err(IPv4Error::BlocksNotAllow);
+                }
+            }
+            None => ()
+        }
+
         Ok(IPv4 {
             ip,
             port,
@@ -350,6 +485,7 @@ mod tests {
             port: ValidatorOption::Allow,
             local: ValidatorOption::NotAllow,
             ipv6: ValidatorOption::NotAllow,
+            blocks: None,
         };
 
         let ipv4 = iv.parse_string(ip).unwrap();
@@ -368,6 +504,7 @@ mod tests {
             port: ValidatorOption::NotAllow,
             local: ValidatorOption::NotAllow,
             ipv6: ValidatorOption::NotAllow,
+            blocks: None,
         };
 
         iv.parse_string(ip).unwrap();
@@ -381,6 +518,7 @@ mod tests {
             port: ValidatorOption::NotAllow,
             local: ValidatorOption::Allow,
             ipv6: ValidatorOption::NotAllow,
+            blocks: None,
         };
 
         iv.parse_string(ip).unwrap();
@@ -394,6 +532,7 @@ mod tests {
             port: ValidatorOption::Allow,
             local: ValidatorOption::NotAllow,
             ipv6: ValidatorOption::NotAllow,
+            blocks: None,
         };
 
         iv.parse_string(ip).unwrap();
@@ -407,6 +546,7 @@ mod tests {
             port: ValidatorOption::NotAllow,
             local: ValidatorOption::NotAllow,
             ipv6: ValidatorOption::Allow,
+            blocks: None,
         };
 
         iv.parse_string(ip).unwrap();
@@ -420,6 +560,7 @@ mod tests {
             port: ValidatorOption::NotAllow,
             local: ValidatorOption::NotAllow,
             ipv6: ValidatorOption::Allow,
+            blocks: None,
         };
 
         iv.parse_string(ip).unwrap();
@@ -433,10 +574,110 @@ mod tests {
             port: ValidatorOption::Allow,
             local: ValidatorOption::NotAllow,
             ipv6: ValidatorOption::Allow,
+            blocks: None,
         };
 
         iv.parse_string(ip).unwrap();
     }
+
+    #[test]
+    fn test_ipv4_reject_leading_zero_octet() {
+        let iv = IPv4Validator {
+            port: ValidatorOption::NotAllow,
+            local: ValidatorOption::NotAllow,
+            ipv6: ValidatorOption::NotAllow,
+            blocks: None,
+        };
+
+        iv.parse_str("168.17.212.007").unwrap_err();
+    }
+
+    #[test]
+    fn test_ipv4_reject_octet_overflow() {
+        let iv = IPv4Validator {
+            port: ValidatorOption::NotAllow,
+            local: ValidatorOption::NotAllow,
+            ipv6: ValidatorOption::NotAllow,
+            blocks: None,
+        };
+
+        iv.parse_str("168.17.212.256").unwrap_err();
+    }
+
+    #[test]
+    fn test_ipv4_reject_overlong_port() {
+        let iv = IPv4Validator {
+            port: ValidatorOption::Allow,
+            local: ValidatorOption::NotAllow,
+            ipv6: ValidatorOption::NotAllow,
+            blocks: None,
+        };
+
+        // More than 5 digits, matching the old `\d{1,5}`-capped regex's rejection.
+        iv.parse_str("1.2.3.4:0000000001").unwrap_err();
+    }
+
+    #[test]
+    fn test_ipv4_to_ip_addr() {
+        let iv = IPv4Validator {
+            port: ValidatorOption::NotAllow,
+            local: ValidatorOption::NotAllow,
+            ipv6: ValidatorOption::NotAllow,
+            blocks: None,
+        };
+
+        let ipv4 = iv.parse_str("168.17.212.1").unwrap();
+
+        assert_eq!(IpAddr::V4(Ipv4Addr::new(168, 17, 212, 1)), IpAddr::from(&ipv4));
+        assert_eq!(IpAddr::V4(Ipv4Addr::new(168, 17, 212, 1)), ipv4.to_ip_addr());
+        assert_eq!(IpAddr::V4(Ipv4Addr::new(168, 17, 212, 1)), IpAddr::from(ipv4));
+    }
+
+    #[test]
+    fn test_ipv4_to_socket_addr_v4() {
+        let iv = IPv4Validator {
+            port: ValidatorOption::Allow,
+            local: ValidatorOption::NotAllow,
+            ipv6: ValidatorOption::NotAllow,
+            blocks: None,
+        };
+
+        let with_port = iv.parse_str("168.17.212.1:8080").unwrap();
+        let socket_addr = SocketAddrV4::try_from(&with_port).unwrap();
+
+        assert_eq!(Ipv4Addr::new(168, 17, 212, 1), *socket_addr.ip());
+        assert_eq!(8080, socket_addr.port());
+
+        let socket_addr = with_port.to_socket_addr().unwrap();
+
+        assert_eq!(Ipv4Addr::new(168, 17, 212, 1), *socket_addr.ip());
+        assert_eq!(8080, socket_addr.port());
+
+        let without_port = iv.parse_str("168.17.212.1").unwrap();
+
+        SocketAddrV4::try_from(&without_port).unwrap_err();
+        assert_eq!(None, without_port.to_socket_addr());
+    }
+
+    #[test]
+    fn test_ipv4_to_socket_addrs() {
+        let iv = IPv4Validator {
+            port: ValidatorOption::Allow,
+            local: ValidatorOption::NotAllow,
+            ipv6: ValidatorOption::NotAllow,
+            blocks: None,
+        };
+
+        let with_port = iv.parse_str("168.17.212.1:8080").unwrap();
+
+        let addr = with_port.to_socket_addrs().unwrap().next().unwrap();
+
+        assert_eq!(SocketAddr::from((Ipv4Addr::new(168, 17, 212, 1), 8080)), addr);
+
+        let without_port = iv.parse_str("168.17.212.1").unwrap();
+
+        without_port.to_socket_addrs().unwrap_err();
+    }
 }
 
 // TODO ----------
@@ -540,6 +781,7 @@ macro_rules! extend {
                     port: $port,
                     local: $local,
                     ipv6: $ipv6,
+                    blocks: None,
                 }
             }
         }
@@ -676,4 +918,624 @@ impl IPv4UnlocalableAllowPort {
 
 extend!(IPv4UnlocalableWithoutPort, ValidatorOption::NotAllow, ValidatorOption::NotAllow, ValidatorOption::Allow);
 
-impl IPv4UnlocalableWithoutPort {}
\ No newline at end of file
+impl IPv4UnlocalableWithoutPort {}
+
+// TODO -----IPv4Network START-----
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum IPv4NetworkError {
+    IncorrectFormat,
+    IncorrectPrefix,
+    UTF8Error(Utf8Error),
+}
+
+impl Display for IPv4NetworkError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for IPv4NetworkError {}
+
+pub type IPv4NetworkResult = Result<IPv4Network, IPv4NetworkError>;
+
+#[derive(Debug, PartialEq)]
+pub struct IPv4NetworkValidator {}
+
+/// An IPv4 address plus a CIDR prefix length, e.g. `192.168.1.0/24`.
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+#[derive(Clone)]
+pub struct IPv4Network {
+    address: Ipv4Addr,
+    prefix: u8,
+    full_network: String,
+}
+
+impl IPv4Network {
+    pub fn get_address(&self) -> &Ipv4Addr {
+        &self.address
+    }
+
+    pub fn get_prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    pub fn get_full_network(&self) -> &str {
+        &self.full_network
+    }
+
+    pub fn get_netmask(&self) -> Ipv4Addr {
+        prefix_to_netmask(self.prefix)
+    }
+
+    pub fn get_network_address(&self) -> Ipv4Addr {
+        apply_mask(self.address, self.get_netmask())
+    }
+
+    pub fn get_broadcast_address(&self) -> Ipv4Addr {
+        let netmask = u32::from(self.get_netmask());
+
+        Ipv4Addr::from(u32::from(self.address) | !netmask)
+    }
+
+    /// Whether the given address falls inside this network.
+    pub fn contains(&self, address: &Ipv4Addr) -> bool {
+        let netmask = self.get_netmask();
+
+        apply_mask(*address, netmask) == self.get_network_address()
+    }
+
+    pub fn into_string(self) -> String {
+        self.full_network
+    }
+}
+
+fn prefix_to_netmask(prefix: u8) -> Ipv4Addr {
+    if prefix == 0 {
+        Ipv4Addr::new(0, 0, 0, 0)
+    } else {
+        Ipv4Addr::from(u32::max_value() << (32 - u32::from(prefix)))
+    }
+}
+
+fn apply_mask(address: Ipv4Addr, netmask: Ipv4Addr) -> Ipv4Addr {
+    Ipv4Addr::from(u32::from(address) & u32::from(netmask))
+}
+
+impl Deref for IPv4Network {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.full_network
+    }
+}
+
+impl Validated for IPv4Network {}
+
+impl Debug for IPv4Network {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        impl_debug_for_tuple_struct!(IPv4Network, f, self, let .0 = self.full_network);
+    }
+}
+
+impl Display for IPv4Network {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.full_network)?;
+        Ok(())
+    }
+}
+
+impl PartialEq for IPv4Network {
+    fn eq(&self, other: &Self) -> bool {
+        self.full_network.eq(&other.full_network)
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        self.full_network.ne(&other.full_network)
+    }
+}
+
+impl Eq for IPv4Network {}
+
+impl Hash for IPv4Network {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.full_network.hash(state);
+    }
+}
+
+impl IPv4NetworkValidator {
+    pub fn is_ipv4_network(&self, full_network: &str) -> bool {
+        self.parse_inner(full_network).is_ok()
+    }
+
+    pub fn parse_string(&self, full_network: String) -> IPv4NetworkResult {
+        let mut network_inner = self.parse_inner(&full_network)?;
+
+        network_inner.full_network = full_network;
+
+        Ok(network_inner)
+    }
+
+    pub fn parse_str(&self, full_network: &str) -> IPv4NetworkResult {
+        let mut network_inner = self.parse_inner(full_network)?;
+
+        network_inner.full_network.push_str(full_network);
+
+        Ok(network_inner)
+    }
+
+    fn parse_inner(&self, full_network: &str) -> IPv4NetworkResult {
+        let mut parts = full_network.splitn(2, '/');
+
+        let address = parts.next().ok_or(IPv4NetworkError::IncorrectFormat)?;
+        let prefix = parts.next().ok_or(IPv4NetworkError::IncorrectFormat)?;
+
+        let address =
+            Ipv4Addr::from_str(address).map_err(|_| IPv4NetworkError::IncorrectFormat)?;
+
+        let prefix: u8 = prefix.parse().map_err(|_| IPv4NetworkError::IncorrectPrefix)?;
+
+        if prefix > 32 {
+            return Err(IPv4NetworkError::IncorrectPrefix);
+        }
+
+        Ok(IPv4Network {
+            address,
+            prefix,
+            full_network: String::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod ipv4_network_tests {
+    use super::*;
+
+    #[test]
+    fn test_ipv4_network_methods() {
+        let network = "192.168.1.0/24".to_string();
+
+        let nv = IPv4NetworkValidator {};
+
+        let network = nv.parse_string(network).unwrap();
+
+        assert_eq!("192.168.1.0/24", network.get_full_network());
+        assert_eq!(24, network.get_prefix());
+        assert_eq!(Ipv4Addr::new(255, 255, 255, 0), network.get_netmask());
+        assert_eq!(Ipv4Addr::new(192, 168, 1, 255), network.get_broadcast_address());
+    }
+
+    #[test]
+    fn test_ipv4_network_contains() {
+        let nv = IPv4NetworkValidator {};
+
+        let network = nv.parse_str("192.168.1.0/24").unwrap();
+
+        assert!(network.contains(&Ipv4Addr::new(192, 168, 1, 42)));
+        assert!(!network.contains(&Ipv4Addr::new(192, 168, 2, 42)));
+    }
+
+    #[test]
+    fn test_ipv4_network_incorrect_prefix() {
+        let nv = IPv4NetworkValidator {};
+
+        nv.parse_str("192.168.1.0/33").unwrap_err();
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_ipv4_network_rkyv_round_trip() {
+        let network = IPv4NetworkValidator {}.parse_str("192.168.1.0/24").unwrap();
+
+        let bytes = rkyv::to_bytes::<_, 64>(&network).unwrap();
+        let archived = unsafe { rkyv::archived_root::<IPv4Network>(&bytes) };
+
+        assert_eq!(network.get_prefix(), archived.prefix);
+        assert_eq!(network.get_full_network(), archived.full_network.as_str());
+    }
+}
+
+// IPv4Network's wrapper struct is itself
+impl ValidatedWrapper for IPv4Network {
+    type Error = IPv4NetworkError;
+
+    fn from_string(full_network: String) -> Result<Self, Self::Error> {
+        IPv4Network::from_string(full_network)
+    }
+
+    fn from_str(full_network: &str) -> Result<Self, Self::Error> {
+        IPv4Network::from_str(full_network)
+    }
+}
+
+impl IPv4Network {
+    pub fn from_string(full_network: String) -> Result<Self, IPv4NetworkError> {
+        IPv4Network::create_validator().parse_string(full_network)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(full_network: &str) -> Result<Self, IPv4NetworkError> {
+        IPv4Network::create_validator().parse_str(full_network)
+    }
+
+    fn create_validator() -> IPv4NetworkValidator {
+        IPv4NetworkValidator {}
+    }
+}
+
+impl FromStr for IPv4Network {
+    type Err = IPv4NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        IPv4Network::from_str(s)
+    }
+}
+
+#[cfg(feature = "rocketly")]
+impl<'a> ::rocket::request::FromFormValue<'a> for IPv4Network {
+    type Error = IPv4NetworkError;
+
+    fn from_form_value(form_value: &'a ::rocket::http::RawStr) -> Result<Self, Self::Error> {
+        IPv4Network::from_string(form_value.url_decode().map_err(|err| IPv4NetworkError::UTF8Error(err))?)
+    }
+}
+
+#[cfg(feature = "rocketly")]
+impl<'a> ::rocket::request::FromParam<'a> for IPv4Network {
+    type Error = IPv4NetworkError;
+
+    fn from_param(param: &'a ::rocket::http::RawStr) -> Result<Self, Self::Error> {
+        IPv4Network::from_string(param.url_decode().map_err(|err| IPv4NetworkError::UTF8Error(err))?)
+    }
+}
+
+#[cfg(feature = "serdely")]
+struct IPv4NetworkStringVisitor;
+
+#[cfg(feature = "serdely")]
+impl<'de> ::serde::de::Visitor<'de> for IPv4NetworkStringVisitor {
+    type Value = IPv4Network;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an IPv4Network string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        IPv4Network::from_str(v).map_err(|err| E::custom(err.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: ::serde::de::Error, {
+        IPv4Network::from_string(v).map_err(|err| E::custom(err.to_string()))
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl<'de> ::serde::Deserialize<'de> for IPv4Network {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>, {
+        deserializer.deserialize_string(IPv4NetworkStringVisitor)
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl ::serde::Serialize for IPv4Network {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer, {
+        serializer.serialize_str(&self.full_network)
+    }
+}
+
+// TODO -----IPv4Set START-----
+
+/// A canonicalized collection of IPv4 address ranges, kept sorted and merged so that membership
+/// checks can be answered with a binary search instead of scanning every range.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IPv4Set {
+    // Inclusive `(start, end)` ranges, sorted by `start` with no overlapping or touching ranges.
+    ranges: Vec<(u32, u32)>,
+}
+
+impl IPv4Set {
+    #[inline]
+    pub fn new() -> IPv4Set {
+        IPv4Set {
+            ranges: Vec::new(),
+        }
+    }
+
+    /// Adds every address between `start` and `end` (inclusive, in either order) to the set.
+    pub fn insert_range(&mut self, start: Ipv4Addr, end: Ipv4Addr) {
+        let start = u32::from(start);
+        let end = u32::from(end);
+
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        self.ranges.push((start, end));
+        self.canonicalize();
+    }
+
+    /// Adds every address covered by a CIDR network to the set.
+    pub fn insert_network(&mut self, network: &IPv4Network) {
+        self.insert_range(network.get_network_address(), network.get_broadcast_address());
+    }
+
+    fn canonicalize(&mut self) {
+        self.ranges.sort_unstable();
+
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(self.ranges.len());
+
+        for &(start, end) in &self.ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 || start - last.1 == 1 => {
+                    if end > last.1 {
+                        last.1 = end;
+                    }
+                },
+                _ => merged.push((start, end)),
+            }
+        }
+
+        self.ranges = merged;
+    }
+
+    /// Whether `address` falls inside any of the set's ranges.
+    pub fn contains(&self, address: &Ipv4Addr) -> bool {
+        let value = u32::from(*address);
+
+        self.ranges
+            .binary_search_by(|&(start, end)| {
+                if value < start {
+                    Ordering::Greater
+                } else if value > end {
+                    Ordering::Less
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// The number of disjoint ranges after canonicalization.
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// The total number of addresses covered by the set.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|&(start, end)| u64::from(end) - u64::from(start) + 1).sum()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Every address present in either set.
+    pub fn union(&self, other: &IPv4Set) -> IPv4Set {
+        let mut ranges = self.ranges.clone();
+        ranges.extend_from_slice(&other.ranges);
+
+        let mut set = IPv4Set { ranges };
+        set.canonicalize();
+        set
+    }
+
+    /// Only the addresses present in both sets.
+    pub fn intersection(&self, other: &IPv4Set) -> IPv4Set {
+        let mut ranges = Vec::new();
+
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_start, a_end) = self.ranges[i];
+            let (b_start, b_end) = other.ranges[j];
+
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IPv4Set { ranges }
+    }
+
+    /// The addresses in this set that are not in `other`.
+    pub fn difference(&self, other: &IPv4Set) -> IPv4Set {
+        let mut ranges = Vec::new();
+
+        for &(start, end) in &self.ranges {
+            let mut cur_start = start;
+            let mut consumed = false;
+
+            for &(b_start, b_end) in &other.ranges {
+                if b_end < cur_start || b_start > end {
+                    continue;
+                }
+
+                if b_start > cur_start {
+                    ranges.push((cur_start, b_start - 1));
+                }
+
+                if b_end >= end {
+                    consumed = true;
+                    break;
+                }
+
+                cur_start = b_end + 1;
+            }
+
+            if !consumed {
+                ranges.push((cur_start, end));
+            }
+        }
+
+        let mut set = IPv4Set { ranges };
+        set.canonicalize();
+        set
+    }
+}
+
+/// Restricts which addresses `IPv4Validator::parse_*` will accept, beyond the coarse `local`
+/// flag: an allow-list requires the address to fall inside the configured `IPv4Set`, a deny-list
+/// requires it to fall outside.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IPv4BlocksRule {
+    Allow(IPv4Set),
+    Deny(IPv4Set),
+}
+
+impl FromIterator<IPv4Network> for IPv4Set {
+    fn from_iter<I: IntoIterator<Item = IPv4Network>>(iter: I) -> Self {
+        let mut set = IPv4Set::new();
+
+        for network in iter {
+            set.insert_network(&network);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod ipv4_set_tests {
+    use super::*;
+
+    #[test]
+    fn test_ipv4_set_contains() {
+        let mut set = IPv4Set::new();
+
+        set.insert_range(Ipv4Addr::new(192, 168, 1, 0), Ipv4Addr::new(192, 168, 1, 255));
+
+        assert!(set.contains(&Ipv4Addr::new(192, 168, 1, 42)));
+        assert!(!set.contains(&Ipv4Addr::new(192, 168, 2, 42)));
+    }
+
+    #[test]
+    fn test_ipv4_set_canonicalizes_adjacent_ranges() {
+        let mut set = IPv4Set::new();
+
+        set.insert_range(Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 0, 127));
+        set.insert_range(Ipv4Addr::new(10, 0, 0, 128), Ipv4Addr::new(10, 0, 0, 255));
+
+        assert_eq!(1, set.range_count());
+        assert_eq!(256, set.len());
+    }
+
+    #[test]
+    fn test_ipv4_set_from_networks() {
+        let nv = IPv4NetworkValidator {};
+
+        let set: IPv4Set = vec![
+            nv.parse_str("192.168.1.0/24").unwrap(),
+            nv.parse_str("192.168.2.0/24").unwrap(),
+        ]
+        .into_iter()
+        .collect();
+
+        // 192.168.1.255 and 192.168.2.0 are adjacent, so the two /24s merge into one range.
+        assert_eq!(1, set.range_count());
+        assert!(set.contains(&Ipv4Addr::new(192, 168, 2, 1)));
+        assert!(!set.contains(&Ipv4Addr::new(192, 168, 3, 1)));
+    }
+
+    #[test]
+    fn test_ipv4_set_union() {
+        let mut a = IPv4Set::new();
+        a.insert_range(Ipv4Addr::new(192, 168, 1, 0), Ipv4Addr::new(192, 168, 1, 127));
+
+        let mut b = IPv4Set::new();
+        b.insert_range(Ipv4Addr::new(192, 168, 1, 128), Ipv4Addr::new(192, 168, 1, 255));
+
+        let union = a.union(&b);
+
+        assert_eq!(1, union.range_count());
+        assert_eq!(256, union.len());
+    }
+
+    #[test]
+    fn test_ipv4_set_intersection() {
+        let mut a = IPv4Set::new();
+        a.insert_range(Ipv4Addr::new(192, 168, 1, 0), Ipv4Addr::new(192, 168, 1, 127));
+
+        let mut b = IPv4Set::new();
+        b.insert_range(Ipv4Addr::new(192, 168, 1, 64), Ipv4Addr::new(192, 168, 1, 255));
+
+        let intersection = a.intersection(&b);
+
+        assert!(intersection.contains(&Ipv4Addr::new(192, 168, 1, 100)));
+        assert!(!intersection.contains(&Ipv4Addr::new(192, 168, 1, 32)));
+        assert_eq!(64, intersection.len());
+    }
+
+    #[test]
+    fn test_ipv4_set_difference() {
+        let mut a = IPv4Set::new();
+        a.insert_range(Ipv4Addr::new(192, 168, 1, 0), Ipv4Addr::new(192, 168, 1, 255));
+
+        let mut b = IPv4Set::new();
+        b.insert_range(Ipv4Addr::new(192, 168, 1, 64), Ipv4Addr::new(192, 168, 1, 127));
+
+        let difference = a.difference(&b);
+
+        assert!(difference.contains(&Ipv4Addr::new(192, 168, 1, 0)));
+        assert!(!difference.contains(&Ipv4Addr::new(192, 168, 1, 100)));
+        assert!(difference.contains(&Ipv4Addr::new(192, 168, 1, 200)));
+        assert_eq!(192, difference.len());
+    }
+
+    #[test]
+    fn test_ipv4_validator_blocks_allow() {
+        let mut allowed = IPv4Set::new();
+        allowed.insert_range(Ipv4Addr::new(192, 168, 1, 0), Ipv4Addr::new(192, 168, 1, 255));
+
+        let iv = IPv4Validator {
+            port: ValidatorOption::NotAllow,
+            local: ValidatorOption::Allow,
+            ipv6: ValidatorOption::NotAllow,
+            blocks: Some(IPv4BlocksRule::Allow(allowed)),
+        };
+
+        iv.parse_str("192.168.1.42").unwrap();
+        iv.parse_str("192.168.2.42").unwrap_err();
+    }
+
+    #[test]
+    fn test_ipv4_validator_blocks_deny() {
+        let mut denied = IPv4Set::new();
+        denied.insert_range(Ipv4Addr::new(192, 168, 1, 0), Ipv4Addr::new(192, 168, 1, 255));
+
+        let iv = IPv4Validator {
+            port: ValidatorOption::NotAllow,
+            local: ValidatorOption::Allow,
+            ipv6: ValidatorOption::NotAllow,
+            blocks: Some(IPv4BlocksRule::Deny(denied)),
+        };
+
+        iv.parse_str("192.168.1.42").unwrap_err();
+        iv.parse_str("192.168.2.42").unwrap();
+    }
+}
+
+// TODO -----IPv4Network END-----
\ No newline at end of file
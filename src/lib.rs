@@ -20,6 +20,10 @@
 //! let dv = DomainValidator {
 //!     port: ValidatorOption::Allow,
 //!     localhost: ValidatorOption::NotAllow,
+//!     fqdn: ValidatorOption::Allow,
+//!     strict: ValidatorOption::Allow,
+//!     escaped: ValidatorOption::Allow,
+//!     user_info: ValidatorOption::Allow,
 //! };
 //!
 //! let domain = dv.parse_string(domain).unwrap();
@@ -109,6 +113,34 @@
 //! let score = Score::from_str("80").unwrap();
 //! ```
 //!
+//! Some validation rules depend on state that is only known at request time, such as a per-request
+//! maximum length, rather than being baked in at macro-expansion time. Use
+//! `validated_customized_string_with_context!` for that; the context value is passed alongside the
+//! input to `from_str_with_context`/`from_string_with_context`,
+//!
+//! ```
+//! #[macro_use] extern crate validators;
+//!
+//! validated_customized_string_with_context!(Name, usize, max_len, s {
+//!     if s.len() > *max_len {
+//!         Err(validators::ValidatedCustomizedStringError::NotMatch)
+//!     } else {
+//!         Ok(s)
+//!     }
+//! }, s {
+//!     if s.len() > *max_len {
+//!         Err(validators::ValidatedCustomizedStringError::NotMatch)
+//!     } else {
+//!         Ok(s.to_string())
+//!     }
+//! });
+//!
+//! let name = Name::from_str_with_context("Ron", &3).unwrap();
+//!
+//! assert_eq!("Ron", name.as_str());
+//! assert!(Name::from_str_with_context("Ron", &2).is_err());
+//! ```
+//!
 //! For a Vec whose length is limited in a range,
 //!
 //! ```
@@ -125,6 +157,26 @@
 //! let names = Names::from_vec(names).unwrap();
 //! ```
 //!
+//! Similarly, for a `HashSet` or `BTreeSet` whose length is limited in a range,
+//!
+//! ```
+//! #[macro_use] extern crate validators;
+//!
+//! use std::collections::HashSet;
+//!
+//! validated_customized_regex_string!(Name, "^[A-Z][a-zA-Z]*( [A-Z][a-zA-Z]*)*$");
+//! validated_customized_ranged_length_hash_set!(Names, 1, 5);
+//!
+//! let mut names = HashSet::new();
+//!
+//! names.insert(Name::from_str("Ron").unwrap());
+//! names.insert(Name::from_str("Magic Len").unwrap());
+//!
+//! let names = Names::from_hash_set(names).unwrap();
+//!
+//! assert_eq!(2, names.as_hash_set().len());
+//! ```
+//!
 //! All validated wrapper types and validated customized structs implement the `ValidatedWrapper` trait.
 //!
 //! Read the documentation to know more helpful customized macros.
@@ -205,6 +257,93 @@
 //! let names = Names::from_vec(names).unwrap();
 //!
 //! assert_eq!("[\"Ron\",\"Magic Len\"]", json!(names).to_string());
+//!
+//! // Deserialization accepts an idiomatic JSON array, too: each element is decoded as `Name`
+//! // before the whole `Vec` is run through `Names::from_vec`, so `Overflow`/`Underflow` still
+//! // apply.
+//! let names: Names<Name> = serde_json::from_str(r#"["Ron", "Magic Len"]"#).unwrap();
+//! ```
+//!
+//! Deserializing a `validated_customized_ranged_length_vec!` type also bails out of the incoming
+//! sequence as soon as its length exceeds the declared maximum, rather than collecting the whole
+//! sequence first and only then running `Names::from_vec`,
+//!
+//! ```rust,ignore
+//! #[macro_use] extern crate validators;
+//! extern crate serde_json;
+//!
+//! validated_customized_regex_string!(Name, "^[A-Z][a-zA-Z]*( [A-Z][a-zA-Z]*)*$");
+//! validated_customized_ranged_length_vec!(Names, 1, 2);
+//!
+//! let err: Result<Names<Name>, _> =
+//!     serde_json::from_str(r#"["Ron", "Magic Len", "Harry Potter"]"#);
+//!
+//! assert!(err.is_err());
+//! ```
+//!
+//! `validated_customized_ranged_number!` also deserializes a number given as a JSON string, not
+//! just a JSON number, which is handy for formats that round-trip numbers through strings to
+//! avoid precision loss,
+//!
+//! ```rust,ignore
+//! #[macro_use] extern crate validators;
+//! extern crate serde_json;
+//!
+//! validated_customized_ranged_number!(Score, u8, 0, 100);
+//!
+//! let score: Score = serde_json::from_str("80").unwrap();
+//! assert_eq!(80, score.get_number());
+//!
+//! let score: Score = serde_json::from_str(r#""80""#).unwrap();
+//! assert_eq!(80, score.get_number());
+//! ```
+//!
+//! `validated_customized_any_number!` builds the same kind of number wrapper, but its
+//! `Deserialize` impl calls `deserialize_any` instead of a fixed `deserialize_u8`/`deserialize_f64`/
+//! etc., which is what a self-describing codec (one that reports the wire type of each value as it
+//! decodes it) needs,
+//!
+//! ```rust,ignore
+//! #[macro_use] extern crate validators;
+//! extern crate serde_json;
+//!
+//! validated_customized_any_number!(Score, u8, s {
+//!     Ok(s.parse::<u8>().map_err(|err| validators::ValidatedCustomizedNumberError::ParseError(err.to_string()))?)
+//! }, s {
+//!     Ok(s.parse::<u8>().map_err(|err| validators::ValidatedCustomizedNumberError::ParseError(err.to_string()))?)
+//! }, n {
+//!     Ok(n)
+//! });
+//!
+//! let score: Score = serde_json::from_str("80").unwrap();
+//! assert_eq!(80, score.get_number());
+//! ```
+//!
+//! The same round trip works for `validated_customized_ranged_length_hash_set!` and
+//! `validated_customized_ranged_length_btree_set!`, except the JSON array is unordered for a
+//! `HashSet`,
+//!
+//! ```rust,ignore
+//! #[macro_use] extern crate validators;
+//! #[macro_use] extern crate serde_json;
+//!
+//! use std::collections::HashSet;
+//!
+//! validated_customized_regex_string!(Name, "^[A-Z][a-zA-Z]*( [A-Z][a-zA-Z]*)*$");
+//! validated_customized_ranged_length_hash_set!(Names, 1, 5);
+//!
+//! let mut names = HashSet::new();
+//!
+//! names.insert(Name::from_str("Ron").unwrap());
+//!
+//! let names = Names::from_hash_set(names).unwrap();
+//!
+//! assert_eq!("[\"Ron\"]", json!(names).to_string());
+//!
+//! // Deserialization accepts an idiomatic JSON array, too: each element is decoded as `Name`
+//! // before the whole set is run through `Names::from_hash_set`, so `Overflow`/`Underflow` still
+//! // apply.
+//! let names: Names<Name> = serde_json::from_str(r#"["Ron"]"#).unwrap();
 //! ```
 
 #![cfg_attr(feature = "nightly", feature(ip))]
@@ -219,6 +358,90 @@ pub extern crate lazy_static;
 #[doc(hidden)]
 pub extern crate rocket;
 
+/// Rocket 0.5 renamed and reworked `FromFormValue`/`FromParam` into `FromFormField`/a `&str`-based
+/// `FromParam`, as a separate major version of the same `rocket` crate. Depend on it under this
+/// alias (`rocket_05 = { package = "rocket", version = "0.5" }`) to keep both the legacy
+/// `rocketly` impls and the modern ones available side by side.
+#[cfg(feature = "rocket_05")]
+#[doc(hidden)]
+pub extern crate rocket as rocket_05;
+
+/// Mirrors Rocket's later `Capped<T>`: a value read from a streamed request body alongside
+/// whether the read stopped because it hit the configured size limit rather than the end of the
+/// data. Returned by the `FromData` impls the `rocketly` feature adds, so a large multipart
+/// upload can be validated without buffering it into a single `String` up front and without
+/// silently accepting a truncated value as whole.
+#[cfg(feature = "rocketly")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Capped<T> {
+    value: T,
+    complete: bool,
+}
+
+#[cfg(feature = "rocketly")]
+impl<T> Capped<T> {
+    #[inline]
+    pub fn new(value: T, complete: bool) -> Capped<T> {
+        Capped {
+            value,
+            complete,
+        }
+    }
+
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// `false` means the incoming data was cut off at the configured size limit.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+}
+
+#[cfg(feature = "rocketly")]
+impl<T> std::ops::Deref for Capped<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Reads `data` into a `String` up to `limit` bytes, the way a `FromData` impl does, without
+/// buffering past that limit: opens the stream at `limit + 1` and, if that extra byte was
+/// reached, reports `complete: false` and truncates back down to `limit` (at the nearest
+/// preceding `char` boundary, since `limit` is a byte count).
+#[cfg(feature = "rocketly")]
+pub fn read_capped_string(data: rocket::Data, limit: u64) -> std::io::Result<Capped<String>> {
+    use std::io::Read;
+
+    let mut buf = String::new();
+
+    data.open(limit + 1).read_to_string(&mut buf)?;
+
+    let complete = (buf.len() as u64) <= limit;
+
+    if !complete {
+        let mut cut = limit as usize;
+
+        while cut > 0 && !buf.is_char_boundary(cut) {
+            cut -= 1;
+        }
+
+        buf.truncate(cut);
+    }
+
+    Ok(Capped::new(buf, complete))
+}
+
 #[cfg(feature = "serdely")]
 #[doc(hidden)]
 #[macro_use]
@@ -282,6 +505,189 @@ pub trait ValidatedWrapper: Validated {
     fn from_str(from_str_input: &str) -> Result<Self, Self::Error>;
 }
 
+/// Like `ValidatedWrapper`, but the validation rule may depend on a context value `C` that is
+/// only known at request time (a per-tenant max length, an allow-list pulled from config, ...)
+/// rather than baked in at macro-expansion time.
+///
+/// `C` defaults to `()`, and every `ValidatedWrapper` gets a `ValidatedWrapperWithContext<()>`
+/// implementation for free below, so existing context-free types don't need to change.
+pub trait ValidatedWrapperWithContext<C = ()>: Validated {
+    type Error: Display + PartialEq + Clone + Debug;
+
+    fn from_string_with_context(from_string_input: String, ctx: &C) -> Result<Self, Self::Error>;
+
+    fn from_str_with_context(from_str_input: &str, ctx: &C) -> Result<Self, Self::Error>;
+}
+
+impl<T: ValidatedWrapper> ValidatedWrapperWithContext<()> for T {
+    type Error = T::Error;
+
+    #[inline]
+    fn from_string_with_context(from_string_input: String, _ctx: &()) -> Result<Self, Self::Error> {
+        T::from_string(from_string_input)
+    }
+
+    #[inline]
+    fn from_str_with_context(from_str_input: &str, _ctx: &()) -> Result<Self, Self::Error> {
+        T::from_str(from_str_input)
+    }
+}
+
+/// A field name -> error messages map, for validating a struct of several `ValidatedWrapper`
+/// fields without stopping at the first failure the way `ValidatedWrapper::from_string` does.
+/// Built up by `validated_struct!`; see `merge` for folding a nested sub-struct's errors in.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationErrors(pub std::collections::HashMap<String, Vec<String>>);
+
+impl ValidationErrors {
+    #[inline]
+    pub fn new() -> ValidationErrors {
+        ValidationErrors(std::collections::HashMap::new())
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Records an error against `field`, appending to that field's list if it already has one.
+    pub fn add(&mut self, field: &str, error: impl Display) {
+        self.0.entry(field.to_string()).or_insert_with(Vec::new).push(error.to_string());
+    }
+
+    /// Folds a nested sub-struct's `ValidationErrors` into `self`, qualifying each of its field
+    /// names with `field` (a failing `city` inside an `address` sub-struct becomes
+    /// `address.city`).
+    pub fn merge(&mut self, field: &str, nested: ValidationErrors) {
+        for (nested_field, errors) in nested.0 {
+            self.0.entry(format!("{}.{}", field, nested_field)).or_insert_with(Vec::new).extend(errors);
+        }
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for ValidationErrors {}
+
+/// Declares a struct whose fields are each built from a raw input, and generates a `validate`
+/// constructor that attempts every field instead of stopping at the first error, accumulating
+/// failures into a `ValidationErrors`. A field is validated with `ValidatedWrapper::from_string`
+/// by default; mark it `#[nested]` when its raw input is itself validated by another
+/// `validated_struct!`-declared type, and its errors are merged in under that field's name
+/// instead. A `#[nested]` field's raw input is passed to that type's `validate` as its only
+/// argument, so the nested type declares a single field; a tuple is not spread over several.
+///
+/// ```rust,ignore
+/// validated_struct!(
+///     pub struct SignupForm {
+///         pub username: Username = String,
+///         pub email: Email = String,
+///         #[nested]
+///         pub address: Address = String,
+///     }
+/// );
+///
+/// match SignupForm::validate(username, email, address) {
+///     Ok(form) => ...,
+///     Err(errors) => ..., // every invalid field, not just the first
+/// }
+/// ```
+#[macro_export]
+macro_rules! validated_struct {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($body:tt)*
+        }
+    ) => {
+        $crate::validated_struct!(@munch
+            $(#[$attr])* $vis struct $name
+            fields: []
+            body: { $($body)* }
+        );
+    };
+
+    (@munch
+        $(#[$attr:meta])* $vis:vis struct $name:ident
+        fields: [$($fields:tt)*]
+        body: { #[nested] $field_vis:vis $field:ident : $t:ty = $raw_ty:ty $(, $($rest:tt)*)? }
+    ) => {
+        $crate::validated_struct!(@munch
+            $(#[$attr])* $vis struct $name
+            fields: [$($fields)* { nested, $field_vis, $field, $t, $raw_ty }]
+            body: { $($($rest)*)? }
+        );
+    };
+
+    (@munch
+        $(#[$attr:meta])* $vis:vis struct $name:ident
+        fields: [$($fields:tt)*]
+        body: { $field_vis:vis $field:ident : $t:ty = $raw_ty:ty $(, $($rest:tt)*)? }
+    ) => {
+        $crate::validated_struct!(@munch
+            $(#[$attr])* $vis struct $name
+            fields: [$($fields)* { plain, $field_vis, $field, $t, $raw_ty }]
+            body: { $($($rest)*)? }
+        );
+    };
+
+    (@munch
+        $(#[$attr:meta])* $vis:vis struct $name:ident
+        fields: [$({ $kind:ident, $field_vis:vis, $field:ident, $t:ty, $raw_ty:ty })*]
+        body: { }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $( $field_vis $field: $t, )*
+        }
+
+        impl $name {
+            pub fn validate( $( $field: $raw_ty ),* ) -> ::std::result::Result<Self, $crate::ValidationErrors> {
+                let mut errors = $crate::ValidationErrors::new();
+
+                $(
+                    let $field = match $crate::validated_struct!(@parse $kind, $t, $field) {
+                        Ok(v) => Some(v),
+                        Err(err) => {
+                            $crate::validated_struct!(@record &mut errors, $kind, $field, err);
+                            None
+                        },
+                    };
+                )*
+
+                if !errors.is_empty() {
+                    return Err(errors);
+                }
+
+                Ok($name {
+                    $( $field: $field.unwrap() ),*
+                })
+            }
+        }
+    };
+
+    (@parse plain, $t:ty, $field:ident) => {
+        <$t as $crate::ValidatedWrapper>::from_string($field.into())
+    };
+
+    (@parse nested, $t:ty, $field:ident) => {
+        <$t>::validate($field)
+    };
+
+    (@record $errors:expr, plain, $field:ident, $err:expr) => {
+        $errors.add(stringify!($field), $err)
+    };
+
+    (@record $errors:expr, nested, $field:ident, $err:expr) => {
+        $errors.merge(stringify!($field), $err)
+    };
+}
+
+pub mod credit_card;
 pub mod domain;
 pub mod email;
 pub mod ipv4;
@@ -301,6 +707,8 @@ pub enum ValidatedCustomizedStringError {
     RegexError(regex::Error),
     NotMatch,
     UTF8Error(Utf8Error),
+    #[cfg(feature = "rocketly")]
+    DataError(String),
 }
 
 impl Display for ValidatedCustomizedStringError {
@@ -335,6 +743,46 @@ impl<'de, V: ValidatedWrapper> serde::de::Visitor<'de> for StringVisitor<V> {
     }
 }
 
+/// A `DeserializeSeed` counterpart to `StringVisitor` that threads a context value `C` through to
+/// `ValidatedWrapperWithContext::from_str_with_context`/`from_string_with_context`, for fields
+/// whose validation rule depends on state outside the deserialized document (e.g. a per-request
+/// config pulled in via `serde::de::DeserializeSeed`).
+#[cfg(feature = "serdely")]
+pub struct StringVisitorWithContext<'a, V, C> {
+    pub ctx: &'a C,
+    pub _marker: std::marker::PhantomData<V>,
+}
+
+#[cfg(feature = "serdely")]
+impl<'de, 'a, V: ValidatedWrapperWithContext<C>, C> serde::de::Visitor<'de> for StringVisitorWithContext<'a, V, C> {
+    type Value = V;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_fmt(format_args!("a string({})", stringify!($name)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_str_with_context(v, self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_string_with_context(v, self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl<'de, 'a, V: ValidatedWrapperWithContext<C>, C> serde::de::DeserializeSeed<'de> for StringVisitorWithContext<'a, V, C> {
+    type Value = V;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        deserializer.deserialize_string(self)
+    }
+}
+
 #[cfg(feature = "serdely")]
 #[doc(hidden)]
 #[macro_export]
@@ -395,6 +843,47 @@ macro_rules! validated_customized_string_struct_implement_from_form_value {
     }
 }
 
+#[cfg(feature = "rocketly")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validated_customized_string_struct_implement_from_data {
+    ( $name:ident ) => {
+        impl ::validators::rocket::data::FromDataSimple for ::validators::Capped<$name> {
+            type Error = ::validators::ValidatedCustomizedStringError;
+
+            fn from_data(request: &::validators::rocket::Request, data: ::validators::rocket::Data) -> ::validators::rocket::data::Outcome<Self, Self::Error> {
+                let limit = request.limits().get("string").unwrap_or(256 * 1024);
+
+                let capped = match ::validators::read_capped_string(data, limit) {
+                    Ok(capped) => capped,
+                    Err(err) => {
+                        return ::validators::rocket::Outcome::Failure((
+                            ::validators::rocket::http::Status::BadRequest,
+                            ::validators::ValidatedCustomizedStringError::DataError(err.to_string()),
+                        ));
+                    }
+                };
+
+                let complete = capped.is_complete();
+
+                match $name::from_string(capped.into_value()) {
+                    Ok(value) => ::validators::rocket::Outcome::Success(::validators::Capped::new(value, complete)),
+                    Err(err) => ::validators::rocket::Outcome::Failure((::validators::rocket::http::Status::UnprocessableEntity, err)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "rocketly"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validated_customized_string_struct_implement_from_data {
+    ( $name:ident ) => {
+
+    }
+}
+
 #[macro_export]
 macro_rules! validated_customized_string_struct {
     ( $name:ident, $field:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block ) => {
@@ -482,6 +971,8 @@ macro_rules! validated_customized_string_struct {
 
         validated_customized_string_struct_implement_from_form_value!($name);
 
+        validated_customized_string_struct_implement_from_data!($name);
+
         validated_customized_string_struct_implement_se_de!($name);
     };
     ( $name:ident, $field:ident, from_string $from_string_input:ident $from_string:block, from_str $from_str_input:ident $from_str:block ) => {
@@ -602,52 +1093,174 @@ macro_rules! validated_customized_regex_string {
 
 // TODO -----ValidatedCustomizedString END-----
 
-// TODO -----ValidatedCustomizedNumber START-----
-
-#[derive(Debug, PartialEq, Clone)]
-pub enum ValidatedCustomizedNumberError {
-    RegexError(regex::Error),
-    ParseError(String),
-    OutRange,
-    NotMatch,
-    UTF8Error(Utf8Error),
-}
+// TODO -----ValidatedCustomizedStringWithContext START-----
 
-impl Display for ValidatedCustomizedNumberError {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Debug::fmt(self, f)
-    }
-}
+#[macro_export]
+macro_rules! validated_customized_string_with_context_struct {
+    ( $name:ident, $ctx:ty, $field:ident, $ctx_input:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block ) => {
+        impl Clone for $name {
+            fn clone(&self) -> Self{
+                let $field = self.$field.clone();
 
-impl Error for ValidatedCustomizedNumberError {}
+                $name{$field}
+            }
+        }
 
-pub trait ValidatedNumberWrapper<T: Number>: ValidatedWrapper {
-    fn from_number(n: T) -> Result<Self, ValidatedCustomizedNumberError>;
-}
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_fmt(format_args!("{}({})", stringify!($name), self.$field))?;
+                Ok(())
+            }
+        }
 
-#[cfg(feature = "serdely")]
-pub struct NumberVisitor<V, T>(pub Vec<V>, pub Vec<T>);
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(&self.$field)?;
+                Ok(())
+            }
+        }
 
-#[cfg(feature = "serdely")]
-impl<'de, V, T> serde::de::Visitor<'de> for NumberVisitor<V, T> where V: ValidatedWrapper + ValidatedNumberWrapper<T>,
-                                                                      T: Number,
-                                                                      u8: NumberAs<T>,
-                                                                      u16: NumberAs<T>,
-                                                                      u32: NumberAs<T>,
-                                                                      u64: NumberAs<T>,
-                                                                      u128: NumberAs<T>,
-                                                                      i8: NumberAs<T>,
-                                                                      i16: NumberAs<T>,
-                                                                      i32: NumberAs<T>,
-                                                                      i64: NumberAs<T>,
-                                                                      i128: NumberAs<T>,
-                                                                      f32: NumberAs<T>,
-                                                                      f64: NumberAs<T> {
-    type Value = V;
+        impl ::std::cmp::PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.$field.eq(&other.$field)
+            }
 
-    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
-        formatter.write_fmt(format_args!("a string({})", stringify!($name)))
-    }
+            fn ne(&self, other: &Self) -> bool {
+                self.$field.ne(&other.$field)
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                self.$field.as_bytes()
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.$field.as_ref()
+            }
+        }
+
+        impl ::validators::Validated for $name {}
+
+        impl ::validators::ValidatedWrapperWithContext<$ctx> for $name {
+            type Error = ::validators::ValidatedCustomizedStringError;
+
+            fn from_string_with_context($from_string_input: String, $ctx_input: &$ctx) -> Result<Self, Self::Error>{
+                $name::from_string_with_context($from_string_input, $ctx_input)
+            }
+
+            fn from_str_with_context($from_str_input: &str, $ctx_input: &$ctx) -> Result<Self, Self::Error>{
+                $name::from_str_with_context($from_str_input, $ctx_input)
+            }
+        }
+
+        impl<'a> $name {
+            pub fn as_str(&'a self) -> &'a str {
+                &self.$field
+            }
+
+            pub fn from_string_with_context($from_string_input: String, $ctx_input: &$ctx) -> Result<Self, ::validators::ValidatedCustomizedStringError>{
+                let $field = match $from_string {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+
+            pub fn from_str_with_context($from_str_input: &str, $ctx_input: &$ctx) -> Result<Self, ::validators::ValidatedCustomizedStringError>{
+                let $field = match $from_str {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+        }
+    };
+}
+
+/// Like `validated_customized_string!`, but the generated `from_string_with_context`/
+/// `from_str_with_context` methods additionally take `$ctx_input: &$ctx`, which is in scope
+/// inside both blocks alongside the input. Useful when the rule itself (a max length, an
+/// allow-list, ...) is only known at request time rather than at macro-expansion time.
+#[macro_export]
+macro_rules! validated_customized_string_with_context {
+    ( $name:ident, $ctx:ty, $ctx_input:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block ) => {
+        struct $name{
+            s: String
+        }
+
+        validated_customized_string_with_context_struct!($name, $ctx, s, $ctx_input, $from_string_input $from_string, $from_str_input $from_str);
+    };
+    ( pub $name:ident, $ctx:ty, $ctx_input:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block ) => {
+        pub struct $name{
+            s: String
+        }
+
+        validated_customized_string_with_context_struct!($name, $ctx, s, $ctx_input, $from_string_input $from_string, $from_str_input $from_str);
+    };
+}
+
+// TODO -----ValidatedCustomizedStringWithContext END-----
+
+// TODO -----ValidatedCustomizedNumber START-----
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ValidatedCustomizedNumberError {
+    RegexError(regex::Error),
+    ParseError(String),
+    OutRange,
+    NotMatch,
+    UTF8Error(Utf8Error),
+}
+
+impl Display for ValidatedCustomizedNumberError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for ValidatedCustomizedNumberError {}
+
+/// Lets `?` convert straight into a Rocket 0.5 form error, so a rejected field surfaces as a
+/// proper 422 with the validation message attached instead of a generic 500.
+#[cfg(feature = "rocket_05")]
+impl<'v> From<ValidatedCustomizedNumberError> for rocket_05::form::Error<'v> {
+    fn from(err: ValidatedCustomizedNumberError) -> Self {
+        rocket_05::form::Error::validation(err.to_string())
+    }
+}
+
+pub trait ValidatedNumberWrapper<T: Number>: ValidatedWrapper {
+    fn from_number(n: T) -> Result<Self, ValidatedCustomizedNumberError>;
+}
+
+#[cfg(feature = "serdely")]
+pub struct NumberVisitor<V, T>(pub Vec<V>, pub Vec<T>);
+
+#[cfg(feature = "serdely")]
+impl<'de, V, T> serde::de::Visitor<'de> for NumberVisitor<V, T> where V: ValidatedWrapper + ValidatedNumberWrapper<T>,
+                                                                      T: Number,
+                                                                      u8: NumberAs<T>,
+                                                                      u16: NumberAs<T>,
+                                                                      u32: NumberAs<T>,
+                                                                      u64: NumberAs<T>,
+                                                                      u128: NumberAs<T>,
+                                                                      i8: NumberAs<T>,
+                                                                      i16: NumberAs<T>,
+                                                                      i32: NumberAs<T>,
+                                                                      i64: NumberAs<T>,
+                                                                      i128: NumberAs<T>,
+                                                                      f32: NumberAs<T>,
+                                                                      f64: NumberAs<T> {
+    type Value = V;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_fmt(format_args!("a string({})", stringify!($name)))
+    }
 
     fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E> where E: serde::de::Error {
         V::from_number(v.number_as()).map_err(|err| {
@@ -724,6 +1337,152 @@ impl<'de, V, T> serde::de::Visitor<'de> for NumberVisitor<V, T> where V: Validat
             E::custom(err.to_string())
         })
     }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_str(v).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_str(&v).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+}
+
+/// Like `ValidatedNumberWrapper`, but `from_number_with_context` also takes a context value `C`,
+/// for a numeric rule (a per-tenant max, ...) only known at request time.
+pub trait ValidatedNumberWrapperWithContext<T: Number, C = ()>: ValidatedWrapperWithContext<C> {
+    fn from_number_with_context(n: T, ctx: &C) -> Result<Self, ValidatedCustomizedNumberError>;
+}
+
+/// A `DeserializeSeed` counterpart to `NumberVisitor` that threads a context value `C` through to
+/// `ValidatedNumberWrapperWithContext::from_number_with_context`.
+#[cfg(feature = "serdely")]
+pub struct NumberVisitorWithContext<'a, V, T, C> {
+    pub ctx: &'a C,
+    pub _marker: std::marker::PhantomData<(V, T)>,
+}
+
+#[cfg(feature = "serdely")]
+impl<'de, 'a, V, T, C> serde::de::Visitor<'de> for NumberVisitorWithContext<'a, V, T, C> where V: ValidatedWrapperWithContext<C> + ValidatedNumberWrapperWithContext<T, C>,
+                                                                                                T: Number,
+                                                                                                u8: NumberAs<T>,
+                                                                                                u16: NumberAs<T>,
+                                                                                                u32: NumberAs<T>,
+                                                                                                u64: NumberAs<T>,
+                                                                                                u128: NumberAs<T>,
+                                                                                                i8: NumberAs<T>,
+                                                                                                i16: NumberAs<T>,
+                                                                                                i32: NumberAs<T>,
+                                                                                                i64: NumberAs<T>,
+                                                                                                i128: NumberAs<T>,
+                                                                                                f32: NumberAs<T>,
+                                                                                                f64: NumberAs<T> {
+    type Value = V;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_fmt(format_args!("a string({})", stringify!($name)))
+    }
+
+    fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    serde_if_integer128! {
+        fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E> where E: serde::de::Error {
+            V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+                E::custom(err.to_string())
+            })
+        }
+    }
+
+    fn visit_i8<E>(self, v: i8) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    fn visit_i16<E>(self, v: i16) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    fn visit_i32<E>(self, v: i32) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    serde_if_integer128! {
+        fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E> where E: serde::de::Error {
+            V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+                E::custom(err.to_string())
+            })
+        }
+    }
+
+    fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> where E: serde::de::Error {
+        V::from_number_with_context(v.number_as(), self.ctx).map_err(|err| {
+            E::custom(err.to_string())
+        })
+    }
+}
+
+#[cfg(feature = "serdely")]
+impl<'de, 'a, V, T, C> serde::de::DeserializeSeed<'de> for NumberVisitorWithContext<'a, V, T, C> where V: ValidatedWrapperWithContext<C> + ValidatedNumberWrapperWithContext<T, C>,
+                                                                                                        T: Number,
+                                                                                                        u8: NumberAs<T>,
+                                                                                                        u16: NumberAs<T>,
+                                                                                                        u32: NumberAs<T>,
+                                                                                                        u64: NumberAs<T>,
+                                                                                                        u128: NumberAs<T>,
+                                                                                                        i8: NumberAs<T>,
+                                                                                                        i16: NumberAs<T>,
+                                                                                                        i32: NumberAs<T>,
+                                                                                                        i64: NumberAs<T>,
+                                                                                                        i128: NumberAs<T>,
+                                                                                                        f32: NumberAs<T>,
+                                                                                                        f64: NumberAs<T> {
+    type Value = V;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: serde::Deserializer<'de> {
+        deserializer.deserialize_any(self)
+    }
 }
 
 #[cfg(feature = "serdely")]
@@ -816,6 +1575,39 @@ macro_rules! validated_customized_number_struct_implement_from_form_value {
     }
 }
 
+/// The Rocket 0.5 counterpart of `validated_customized_number_struct_implement_from_form_value!`:
+/// a `FromFormField` impl (covering both the sync `from_value` path and its `from_data` default)
+/// plus the reworked `FromParam<'a>` over `&'a str`, both delegating to `from_str`.
+#[cfg(feature = "rocket_05")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validated_customized_number_struct_implement_from_form_field {
+    ( $name:ident ) => {
+        impl<'v> ::validators::rocket_05::form::FromFormField<'v> for $name {
+            fn from_value(field: ::validators::rocket_05::form::ValueField<'v>) -> ::validators::rocket_05::form::Result<'v, Self> {
+                Ok($name::from_str(field.value)?)
+            }
+        }
+
+        impl<'a> ::validators::rocket_05::request::FromParam<'a> for $name {
+            type Error = ::validators::ValidatedCustomizedNumberError;
+
+            fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+                $name::from_str(param)
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "rocket_05"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validated_customized_number_struct_implement_from_form_field {
+    ( $name:ident ) => {
+
+    }
+}
+
 #[macro_export]
 macro_rules! validated_customized_number_struct {
     ( $name:ident, $field:ident, $t:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_number_input:ident $from_number:block ) => {
@@ -906,6 +1698,8 @@ macro_rules! validated_customized_number_struct {
 
         validated_customized_number_struct_implement_from_form_value!($name);
 
+        validated_customized_number_struct_implement_from_form_field!($name);
+
         validated_customized_number_struct_implement_se_de!($name, $t);
     };
     ( $name:ident, $field:ident, $t:ident, from_string $from_string_input:ident $from_string:block, from_str $from_str_input:ident $from_str:block, from_number $from_number_input:ident $from_number:block ) => {
@@ -982,35 +1776,195 @@ macro_rules! validated_customized_number {
     };
 }
 
+#[cfg(feature = "serdely")]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! validated_customized_regex_number_struct {
-    ( $name:ident, $field:ident, $t:ident, $re:expr ) => {
-        validated_customized_number_struct!($name, $field, $t,
-        input {
-            let re = ::validators::regex::RegexBuilder::new($re).size_limit(::validators::REGEX_SIZE_LIMIT).build().map_err(|err| ::validators::ValidatedCustomizedNumberError::RegexError(err))?;
-
-            if re.is_match(&input) {
-                Ok(input.parse::<$t>().map_err(|err|::validators::ValidatedCustomizedNumberError::ParseError(err.to_string()))?)
-            } else{
-                Err(::validators::ValidatedCustomizedNumberError::NotMatch)
-            }
-        },
-        input {
-            let re = ::validators::regex::RegexBuilder::new($re).size_limit(::validators::REGEX_SIZE_LIMIT).build().map_err(|err| ::validators::ValidatedCustomizedNumberError::RegexError(err))?;
-
-            if re.is_match(&input) {
-                Ok(input.parse::<$t>().map_err(|err|::validators::ValidatedCustomizedNumberError::ParseError(err.to_string()))?)
-            } else{
-                Err(::validators::ValidatedCustomizedNumberError::NotMatch)
+macro_rules! validated_customized_number_struct_implement_se_de_any {
+    ( $name:ident, $t:ident ) => {
+        impl<'de> ::validators::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::validators::serde::Deserializer<'de> {
+                deserializer.deserialize_any(::validators::NumberVisitor(Vec::<$name>::new(), Vec::<$t>::new()))
             }
-        },
-        input {
-            let input = input.to_string();
-
-            let re = ::validators::regex::RegexBuilder::new($re).size_limit(::validators::REGEX_SIZE_LIMIT).build().map_err(|err| ::validators::ValidatedCustomizedNumberError::RegexError(err))?;
+        }
 
-            if re.is_match(&input) {
-                Ok(input.parse::<$t>().map_err(|err|::validators::ValidatedCustomizedNumberError::ParseError(err.to_string()))?)
+        impl ::validators::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: ::validators::serde::Serializer {
+                match stringify!($t) {
+                    "u8" => serializer.serialize_u8(self.get_number() as u8),
+                    "u16" => serializer.serialize_u16(self.get_number() as u16),
+                    "u32" => serializer.serialize_u32(self.get_number() as u32),
+                    "u64" => serializer.serialize_u64(self.get_number() as u64),
+                    "u128" => serializer.serialize_u128(self.get_number() as u128),
+                    "i8" => serializer.serialize_i8(self.get_number() as i8),
+                    "i16" => serializer.serialize_i16(self.get_number() as i16),
+                    "i32" => serializer.serialize_i32(self.get_number() as i32),
+                    "i64" => serializer.serialize_i64(self.get_number() as i64),
+                    "i128" => serializer.serialize_i128(self.get_number() as i128),
+                    "f32" => serializer.serialize_f32(self.get_number() as f32),
+                    "f64" => serializer.serialize_f64(self.get_number() as f64),
+                    _ => panic!("impossible")
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "serdely"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validated_customized_number_struct_implement_se_de_any {
+    ( $name:ident, $t:ident ) => {
+
+    }
+}
+
+/// Like `validated_customized_number_struct!`, except the generated `Deserialize` impl calls
+/// `deserialize_any` instead of dispatching to a fixed `deserialize_u8`/`deserialize_f64`/etc.
+/// Needed for self-describing codecs (the format itself reports the concrete number kind, as in
+/// the Preserves data model) where hard-coding the wire type ahead of time is wrong.
+#[macro_export]
+macro_rules! validated_customized_any_number_struct {
+    ( $name:ident, $field:ident, $t:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_number_input:ident $from_number:block ) => {
+        impl Clone for $name {
+            fn clone(&self) -> Self{
+                let $field = self.$field;
+
+                $name{$field}
+            }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_fmt(format_args!("{}({})", stringify!($name), self.$field))?;
+                Ok(())
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_fmt(format_args!("{}", self.$field))?;
+                Ok(())
+            }
+        }
+
+        impl ::std::cmp::PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.$field == other.$field
+            }
+
+            fn ne(&self, other: &Self) -> bool {
+                self.$field != other.$field
+            }
+        }
+
+        impl ::validators::Validated for $name {}
+
+        impl ::validators::ValidatedWrapper for $name {
+            type Error = ::validators::ValidatedCustomizedNumberError;
+
+            fn from_string($from_string_input: String) -> Result<Self, Self::Error>{
+                $name::from_string($from_string_input)
+            }
+
+            fn from_str($from_str_input: &str) -> Result<Self, Self::Error>{
+                $name::from_str($from_str_input)
+            }
+        }
+
+        impl<T: ::validators::number_as::Number> ::validators::ValidatedNumberWrapper<T> for $name {
+            fn from_number($from_number_input: T) -> Result<Self, ::validators::ValidatedCustomizedNumberError>{
+                $name::from_number($from_number_input.number_as())
+            }
+        }
+
+        impl $name {
+            pub fn get_number(&self) -> $t {
+                self.$field
+            }
+
+            pub fn from_string($from_string_input: String) -> Result<Self, ::validators::ValidatedCustomizedNumberError>{
+                let $field = match $from_string {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+
+            pub fn from_str($from_str_input: &str) -> Result<Self, ::validators::ValidatedCustomizedNumberError>{
+                let $field = match $from_str {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+
+            pub fn from_number($from_number_input: $t) -> Result<Self, ::validators::ValidatedCustomizedNumberError>{
+                let $field = match $from_number {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+        }
+
+        validated_customized_number_struct_implement_from_form_value!($name);
+
+        validated_customized_number_struct_implement_from_form_field!($name);
+
+        validated_customized_number_struct_implement_se_de_any!($name, $t);
+    };
+}
+
+#[macro_export]
+macro_rules! validated_customized_any_number {
+    ( $name:ident, $t:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_number_input:ident $from_number:block ) => {
+        struct $name{
+            n: $t
+        }
+
+        validated_customized_any_number_struct!($name, n, $t, $from_string_input $from_string, $from_str_input $from_str, $from_number_input $from_number);
+    };
+    ( pub $name:ident, $t:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_number_input:ident $from_number:block ) => {
+        pub struct $name{
+            n: $t
+        }
+
+        validated_customized_any_number_struct!($name, n, $t, $from_string_input $from_string, $from_str_input $from_str, $from_number_input $from_number);
+    };
+}
+
+#[macro_export]
+macro_rules! validated_customized_regex_number_struct {
+    ( $name:ident, $field:ident, $t:ident, $re:expr ) => {
+        validated_customized_number_struct!($name, $field, $t,
+        input {
+            let re = ::validators::regex::RegexBuilder::new($re).size_limit(::validators::REGEX_SIZE_LIMIT).build().map_err(|err| ::validators::ValidatedCustomizedNumberError::RegexError(err))?;
+
+            if re.is_match(&input) {
+                Ok(input.parse::<$t>().map_err(|err|::validators::ValidatedCustomizedNumberError::ParseError(err.to_string()))?)
+            } else{
+                Err(::validators::ValidatedCustomizedNumberError::NotMatch)
+            }
+        },
+        input {
+            let re = ::validators::regex::RegexBuilder::new($re).size_limit(::validators::REGEX_SIZE_LIMIT).build().map_err(|err| ::validators::ValidatedCustomizedNumberError::RegexError(err))?;
+
+            if re.is_match(&input) {
+                Ok(input.parse::<$t>().map_err(|err|::validators::ValidatedCustomizedNumberError::ParseError(err.to_string()))?)
+            } else{
+                Err(::validators::ValidatedCustomizedNumberError::NotMatch)
+            }
+        },
+        input {
+            let input = input.to_string();
+
+            let re = ::validators::regex::RegexBuilder::new($re).size_limit(::validators::REGEX_SIZE_LIMIT).build().map_err(|err| ::validators::ValidatedCustomizedNumberError::RegexError(err))?;
+
+            if re.is_match(&input) {
+                Ok(input.parse::<$t>().map_err(|err|::validators::ValidatedCustomizedNumberError::ParseError(err.to_string()))?)
             } else{
                 Err(::validators::ValidatedCustomizedNumberError::NotMatch)
             }
@@ -1180,6 +2134,11 @@ pub enum ValidatedCustomizedVecError {
     Underflow,
     NotSupport,
     UTF8Error(Utf8Error),
+    /// Every element-index/error-message pair collected by `from_iter_validated`, instead of
+    /// bailing out on the first bad element; a failed length/custom check after that is a single
+    /// `(0, message)` pair. Stores rendered messages rather than the original
+    /// error values since those aren't required to be `Clone`/`PartialEq` themselves.
+    Multiple(Vec<(usize, String)>),
 }
 
 impl Display for ValidatedCustomizedVecError {
@@ -1192,6 +2151,14 @@ impl Error for ValidatedCustomizedVecError {}
 
 pub trait ValidatedVecWrapper<T: ValidatedWrapper>: ValidatedWrapper {
     fn from_vec(v: Vec<T>) -> Result<Self, ValidatedCustomizedVecError>;
+
+    /// The most elements this wrapper can ever accept, if fixed. `VecVisitor` uses this to bail
+    /// out of deserialization as soon as the running count exceeds it, instead of collecting the
+    /// whole (possibly huge) sequence before validating its length.
+    #[inline]
+    fn max_len() -> Option<usize> {
+        None
+    }
 }
 
 #[cfg(feature = "serdely")]
@@ -1206,14 +2173,24 @@ impl<'de, V: ValidatedVecWrapper<T>, T: ValidatedWrapper + serde::Deserialize<'d
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
-        let mut v = Vec::<T>::new();
+        // Mirrors serde's own "cautious" preallocation: a malicious/incorrect size hint can't
+        // force an allocation bigger than this cap up front.
+        const PREALLOCATE_CAP: usize = 4096;
+
+        let cap = seq.size_hint().unwrap_or(0).min(PREALLOCATE_CAP);
+
+        let mut v = Vec::<T>::with_capacity(cap);
+
+        while let Some(e) = seq.next_element()? {
+            v.push(e);
 
-        loop {
-            match seq.next_element()? {
-                Some(e) => {
-                    v.push(e);
+            if let Some(max_len) = V::max_len() {
+                if v.len() > max_len {
+                    return Err(serde::de::Error::custom(format!(
+                        "the length of this vec must be equal to or less than {}",
+                        max_len
+                    )));
                 }
-                None => { break; }
             }
         }
 
@@ -1228,6 +2205,9 @@ impl<'de, V: ValidatedVecWrapper<T>, T: ValidatedWrapper + serde::Deserialize<'d
 #[macro_export]
 macro_rules! validated_customized_vec_struct_implement_se_de {
      ( $name:ident ) => {
+        // Each element is decoded via `T`'s own `Deserialize` (so idiomatic JSON arrays like
+        // `["a", "b"]` work directly), and the collected `Vec<T>` is then run through
+        // `$name::from_vec`, so length/custom checks still execute and surface as `de::Error`.
         impl<'de, T: ::validators::ValidatedWrapper + ::validators::serde::Deserialize<'de>> ::validators::serde::Deserialize<'de> for $name<T> {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::validators::serde::Deserializer<'de> {
                 deserializer.deserialize_seq(::validators::VecVisitor(Vec::<$name<T>>::new(), Vec::<T>::new()))
@@ -1272,6 +2252,38 @@ macro_rules! validated_customized_vec_struct_implement_from_form_value {
             }
         }
 
+        // `FromFormValue`/`FromParam` above parse one raw string that's already been split by the
+        // caller. Real HTML forms submit repeated keys instead (`tags=a&tags=b&tags=c`), which
+        // Rocket surfaces through `FromForm`'s multi-value iteration rather than `FromFormValue`.
+        impl<'f, T: ::validators::ValidatedWrapper> ::validators::rocket::request::FromForm<'f> for $name<T> {
+            type Error = ::validators::ValidatedCustomizedVecError;
+
+            fn from_form(items: &mut ::validators::rocket::request::FormItems<'f>, _strict: bool) -> Result<Self, Self::Error> {
+                let mut v = Vec::new();
+                let mut errors = Vec::new();
+
+                for (index, item) in items.enumerate() {
+                    let value = match item.value.url_decode() {
+                        Ok(value) => value,
+                        Err(err) => {
+                            errors.push((index, err.to_string()));
+                            continue;
+                        }
+                    };
+
+                    match T::from_str(&value) {
+                        Ok(parsed) => v.push(parsed),
+                        Err(err) => errors.push((index, err.to_string())),
+                    }
+                }
+
+                if !errors.is_empty() {
+                    return Err(::validators::ValidatedCustomizedVecError::Multiple(errors));
+                }
+
+                $name::from_vec(v)
+            }
+        }
     }
 }
 
@@ -1286,7 +2298,7 @@ macro_rules! validated_customized_vec_struct_implement_from_form_value {
 
 #[macro_export]
 macro_rules! validated_customized_vec_struct {
-    ( $name:ident, $field:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_vec_input:ident $from_vec:block ) => {
+    ( $name:ident, $field:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_vec_input:ident $from_vec:block $(, max $max:expr)? $(, filter $filter_input:ident $filter:block)? ) => {
         impl<T: ::validators::ValidatedWrapper> Clone for $name<T> {
             fn clone(&self) -> Self{
                 let $field = self.$field.clone();
@@ -1369,6 +2381,13 @@ macro_rules! validated_customized_vec_struct {
             fn from_vec($from_vec_input: Vec<T>) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
                 $name::from_vec($from_vec_input)
             }
+
+            $(
+                #[inline]
+                fn max_len() -> Option<usize> {
+                    Some($max)
+                }
+            )?
         }
 
         impl<T: ::validators::ValidatedWrapper> $name<T> {
@@ -1399,6 +2418,10 @@ macro_rules! validated_customized_vec_struct {
             }
 
             pub fn from_vec($from_vec_input: Vec<T>) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                $(
+                    let $from_vec_input: Vec<T> = $from_vec_input.into_iter().map(|$filter_input| $filter).collect();
+                )?
+
                 let $field = match $from_vec {
                     Ok(s)=> s,
                     Err(e)=> return Err(e)
@@ -1406,6 +2429,30 @@ macro_rules! validated_customized_vec_struct {
 
                 Ok($name{$field})
             }
+
+            /// Like `from_vec`, but validates every element of `iter` instead of stopping at the
+            /// first bad one: every failing `T::from_string` call is collected as an
+            /// `(index, message)` pair. Only runs the length/custom check (and returns a plain
+            /// `$name`) once every element has parsed successfully.
+            pub fn from_iter_validated<I: IntoIterator<Item = String>>(iter: I) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                let mut values = Vec::new();
+                let mut errors = Vec::new();
+
+                for (index, item) in iter.into_iter().enumerate() {
+                    match T::from_string(item) {
+                        Ok(v) => values.push(v),
+                        Err(e) => errors.push((index, e.to_string())),
+                    }
+                }
+
+                if !errors.is_empty() {
+                    return Err(::validators::ValidatedCustomizedVecError::Multiple(errors));
+                }
+
+                $name::from_vec(values).map_err(|e| {
+                    ::validators::ValidatedCustomizedVecError::Multiple(vec![(0, e.to_string())])
+                })
+            }
         }
 
          validated_customized_vec_struct_implement_from_form_value!($name);
@@ -1413,6 +2460,9 @@ macro_rules! validated_customized_vec_struct {
     };
 }
 
+/// The `filter $filter_input:ident $filter:block` arms below run `$filter` over each element of
+/// the incoming `Vec<T>` before `from_vec`'s length/custom check, letting callers normalize
+/// elements (trim, lowercase, collapse separators, ...) instead of only validating them.
 #[macro_export]
 macro_rules! validated_customized_vec {
     ( $name:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_vec_input:ident $from_vec:block ) => {
@@ -1465,6 +2515,20 @@ macro_rules! validated_customized_vec {
     ( pub $name:ident, from_str $from_str_input:ident $from_str:block, from_vec $from_vec_input:ident $from_vec:block, from_string $from_string_input:ident $from_string:block ) => {
         validated_customized_vec!(pub $name, $from_string_input $from_string, $from_str_input $from_str, $from_vec_input $from_vec);
     };
+    ( $name:ident, filter $filter_input:ident $filter:block, from_string $from_string_input:ident $from_string:block, from_str $from_str_input:ident $from_str:block, from_vec $from_vec_input:ident $from_vec:block ) => {
+        struct $name<T: ::validators::ValidatedWrapper> {
+            v: Vec<T>
+        }
+
+        validated_customized_vec_struct!($name, v, $from_string_input $from_string, $from_str_input $from_str, $from_vec_input $from_vec, filter $filter_input $filter);
+    };
+    ( pub $name:ident, filter $filter_input:ident $filter:block, from_string $from_string_input:ident $from_string:block, from_str $from_str_input:ident $from_str:block, from_vec $from_vec_input:ident $from_vec:block ) => {
+        pub struct $name<T: ::validators::ValidatedWrapper> {
+            v: Vec<T>
+        }
+
+        validated_customized_vec_struct!($name, v, $from_string_input $from_string, $from_str_input $from_str, $from_vec_input $from_vec, filter $filter_input $filter);
+    };
 }
 
 #[macro_export]
@@ -1483,7 +2547,7 @@ macro_rules! validated_customized_ranged_length_vec_struct {
             } else {
                 Ok(input)
             }
-        });
+        }, max $max);
     };
 }
 
@@ -1540,3 +2604,657 @@ macro_rules! validated_customized_ranged_length_vec {
 }
 
 // TODO -----ValidatedCustomizedRangedLengthVec End-----
+
+// TODO -----ValidatedCustomizedVecWithContext START-----
+
+#[macro_export]
+macro_rules! validated_customized_vec_with_context_struct {
+    ( $name:ident, $ctx:ty, $field:ident, $ctx_input:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_vec_input:ident $from_vec:block ) => {
+        impl<T: ::validators::ValidatedWrapper> Clone for $name<T> {
+            fn clone(&self) -> Self{
+                let $field = self.$field.clone();
+
+                $name{$field}
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::std::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_fmt(format_args!("{}[", stringify!($name)))?;
+
+                let len = self.$field.len();
+
+                if len > 0 {
+                    for n in self.$field.iter().skip(1) {
+                        ::std::fmt::Debug::fmt(n, f)?;
+
+                        f.write_str(", ")?;
+                    }
+
+                    ::std::fmt::Debug::fmt(&self.$field[len - 1], f)?;
+                }
+
+                f.write_str("]")?;
+
+                Ok(())
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::std::fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str("[")?;
+
+                let len = self.$field.len();
+
+                if len > 0 {
+                    for n in self.$field.iter().skip(1) {
+                        ::std::fmt::Display::fmt(n, f)?;
+
+                        f.write_str(", ")?;
+                    }
+
+                    ::std::fmt::Display::fmt(&self.$field[len - 1], f)?;
+                }
+
+                f.write_str("]")?;
+
+                Ok(())
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::std::cmp::PartialEq for $name<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.$field == other.$field
+            }
+
+            fn ne(&self, other: &Self) -> bool {
+                self.$field != other.$field
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::validators::Validated for $name<T> {}
+
+        impl<T: ::validators::ValidatedWrapper> ::validators::ValidatedWrapperWithContext<$ctx> for $name<T> {
+            type Error = ::validators::ValidatedCustomizedVecError;
+
+            fn from_string_with_context($from_string_input: String, $ctx_input: &$ctx) -> Result<Self, Self::Error>{
+                $name::from_string_with($from_string_input, $ctx_input)
+            }
+
+            fn from_str_with_context($from_str_input: &str, $ctx_input: &$ctx) -> Result<Self, Self::Error>{
+                $name::from_str_with($from_str_input, $ctx_input)
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::validators::ValidatedWrapper for $name<T> where $ctx: Default {
+            type Error = ::validators::ValidatedCustomizedVecError;
+
+            fn from_string(from_string_input: String) -> Result<Self, Self::Error>{
+                $name::from_string_with(from_string_input, &<$ctx>::default())
+            }
+
+            fn from_str(from_str_input: &str) -> Result<Self, Self::Error>{
+                $name::from_str_with(from_str_input, &<$ctx>::default())
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> $name<T> {
+            pub fn as_vec(&self) -> &Vec<T> {
+                &self.$field
+            }
+
+            pub fn into_vec(self) -> Vec<T> {
+                self.$field
+            }
+
+            pub fn from_string_with($from_string_input: String, $ctx_input: &$ctx) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                let $field = match $from_string {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+
+            pub fn from_str_with($from_str_input: &str, $ctx_input: &$ctx) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                let $field = match $from_str {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+
+            pub fn from_vec_with($from_vec_input: Vec<T>, $ctx_input: &$ctx) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                let $field = match $from_vec {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> $name<T> where $ctx: Default {
+            pub fn from_string(from_string_input: String) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                $name::from_string_with(from_string_input, &<$ctx>::default())
+            }
+
+            pub fn from_str(from_str_input: &str) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                $name::from_str_with(from_str_input, &<$ctx>::default())
+            }
+
+            pub fn from_vec(from_vec_input: Vec<T>) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                $name::from_vec_with(from_vec_input, &<$ctx>::default())
+            }
+        }
+    };
+}
+
+/// Like `validated_customized_vec!`, but `from_string_with`/`from_str_with`/`from_vec_with` take
+/// an extra `$ctx_input: &$ctx` parameter that's in scope inside all three blocks, for vec rules
+/// that depend on state only known at request time (a configured max length, a DB-backed
+/// allow-list, a feature flag) rather than literals baked in at macro-expansion time. When `$ctx`
+/// is `Default`, the plain `from_string`/`from_str`/`from_vec` methods are also generated,
+/// delegating to the `_with` versions with the context's default value, so existing non-context
+/// callers keep working.
+#[macro_export]
+macro_rules! validated_customized_vec_with_context {
+    ( $name:ident, $ctx:ty, $ctx_input:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_vec_input:ident $from_vec:block ) => {
+        struct $name<T: ::validators::ValidatedWrapper> {
+            v: Vec<T>
+        }
+
+        validated_customized_vec_with_context_struct!($name, $ctx, v, $ctx_input, $from_string_input $from_string, $from_str_input $from_str, $from_vec_input $from_vec);
+    };
+    ( pub $name:ident, $ctx:ty, $ctx_input:ident, $from_string_input:ident $from_string:block, $from_str_input:ident $from_str:block, $from_vec_input:ident $from_vec:block ) => {
+        pub struct $name<T: ::validators::ValidatedWrapper> {
+            v: Vec<T>
+        }
+
+        validated_customized_vec_with_context_struct!($name, $ctx, v, $ctx_input, $from_string_input $from_string, $from_str_input $from_str, $from_vec_input $from_vec);
+    };
+}
+
+// TODO -----ValidatedCustomizedVecWithContext End-----
+
+// TODO -----ValidatedCustomizedRangedLengthSet START-----
+
+pub trait ValidatedSetWrapper<T: ValidatedWrapper>: ValidatedWrapper {
+    #[inline]
+    #[allow(unused_variables)]
+    fn from_hash_set(s: std::collections::HashSet<T>) -> Result<Self, ValidatedCustomizedVecError> {
+        Err(ValidatedCustomizedVecError::NotSupport)
+    }
+
+    #[inline]
+    #[allow(unused_variables)]
+    fn from_btree_set(s: std::collections::BTreeSet<T>) -> Result<Self, ValidatedCustomizedVecError> {
+        Err(ValidatedCustomizedVecError::NotSupport)
+    }
+}
+
+#[cfg(feature = "serdely")]
+pub struct HashSetVisitor<V, T>(pub Vec<V>, pub Vec<T>);
+
+#[cfg(feature = "serdely")]
+impl<'de, V: ValidatedSetWrapper<T>, T: ValidatedWrapper + Eq + std::hash::Hash + serde::Deserialize<'de>> serde::de::Visitor<'de> for HashSetVisitor<V, T> {
+    type Value = V;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_fmt(format_args!("a string({})", stringify!($name)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+        const PREALLOCATE_CAP: usize = 4096;
+
+        let cap = seq.size_hint().unwrap_or(0).min(PREALLOCATE_CAP);
+
+        let mut s = std::collections::HashSet::<T>::with_capacity(cap);
+
+        while let Some(e) = seq.next_element()? {
+            s.insert(e);
+        }
+
+        Ok(V::from_hash_set(s).map_err(|err| {
+            serde::de::Error::custom(err.to_string())
+        })?)
+    }
+}
+
+#[cfg(feature = "serdely")]
+pub struct BTreeSetVisitor<V, T>(pub Vec<V>, pub Vec<T>);
+
+#[cfg(feature = "serdely")]
+impl<'de, V: ValidatedSetWrapper<T>, T: ValidatedWrapper + Ord + serde::Deserialize<'de>> serde::de::Visitor<'de> for BTreeSetVisitor<V, T> {
+    type Value = V;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_fmt(format_args!("a string({})", stringify!($name)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: serde::de::SeqAccess<'de> {
+        let mut s = std::collections::BTreeSet::<T>::new();
+
+        while let Some(e) = seq.next_element()? {
+            s.insert(e);
+        }
+
+        Ok(V::from_btree_set(s).map_err(|err| {
+            serde::de::Error::custom(err.to_string())
+        })?)
+    }
+}
+
+#[macro_export]
+macro_rules! validated_customized_hash_set_struct {
+    ( $name:ident, $field:ident, input $from_set_input:ident $from_set:block ) => {
+        impl<T: ::validators::ValidatedWrapper> Clone for $name<T> where T: Clone {
+            fn clone(&self) -> Self{
+                let $field = self.$field.clone();
+
+                $name{$field}
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::std::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Debug::fmt(&self.$field, f)
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::std::cmp::PartialEq for $name<T> where T: Eq + ::std::hash::Hash {
+            fn eq(&self, other: &Self) -> bool {
+                self.$field == other.$field
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::validators::Validated for $name<T> {}
+
+        impl<T: ::validators::ValidatedWrapper> ::validators::ValidatedWrapper for $name<T> {
+            type Error = ::validators::ValidatedCustomizedVecError;
+
+            fn from_string(_from_string_input: String) -> Result<Self, Self::Error>{
+                Err(::validators::ValidatedCustomizedVecError::NotSupport)
+            }
+
+            fn from_str(_from_str_input: &str) -> Result<Self, Self::Error>{
+                Err(::validators::ValidatedCustomizedVecError::NotSupport)
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::validators::ValidatedSetWrapper<T> for $name<T> {
+            fn from_hash_set($from_set_input: ::std::collections::HashSet<T>) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                $name::from_hash_set($from_set_input)
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> $name<T> {
+            pub fn as_hash_set(&self) -> &::std::collections::HashSet<T> {
+                &self.$field
+            }
+
+            pub fn into_hash_set(self) -> ::std::collections::HashSet<T> {
+                self.$field
+            }
+
+            pub fn from_hash_set($from_set_input: ::std::collections::HashSet<T>) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                let $field = match $from_set {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+        }
+
+        validated_customized_hash_set_struct_implement_se_de!($name);
+    }
+}
+
+#[cfg(feature = "serdely")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validated_customized_hash_set_struct_implement_se_de {
+     ( $name:ident ) => {
+        impl<'de, T: ::validators::ValidatedWrapper + Eq + ::std::hash::Hash + ::validators::serde::Deserialize<'de>> ::validators::serde::Deserialize<'de> for $name<T> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::validators::serde::Deserializer<'de> {
+                deserializer.deserialize_seq(::validators::HashSetVisitor(Vec::<$name<T>>::new(), Vec::<T>::new()))
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper + ::validators::serde::Serialize> ::validators::serde::Serialize for $name<T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: ::validators::serde::Serializer {
+                serializer.collect_seq(self.as_hash_set().iter())
+            }
+        }
+     }
+}
+
+#[cfg(not(feature = "serdely"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validated_customized_hash_set_struct_implement_se_de {
+    ( $name:ident ) => {
+
+    }
+}
+
+#[macro_export]
+macro_rules! validated_customized_ranged_length_hash_set_struct {
+    ( $name:ident, $field:expr, $min:expr, $max:expr ) => {
+        validated_customized_hash_set_struct!($name, v, input s {
+            let len = s.len();
+
+            if len > $max {
+                Err(::validators::ValidatedCustomizedVecError::Overflow)
+            } else if len < $min {
+                Err(::validators::ValidatedCustomizedVecError::Underflow)
+            } else {
+                Ok(s)
+            }
+        });
+    };
+}
+
+#[macro_export]
+macro_rules! validated_customized_ranged_length_hash_set {
+    ( $name:ident, $min:expr, $max:expr ) => {
+        struct $name<T: ::validators::ValidatedWrapper> {
+            v: ::std::collections::HashSet<T>
+        }
+
+        validated_customized_ranged_length_hash_set_struct!($name, v, $min, $max);
+    };
+    ( $name:ident, $equal:expr ) => {
+        validated_customized_ranged_length_hash_set!($name, $equal, $equal);
+    };
+    ( pub $name:ident, $min:expr, $max:expr ) => {
+        pub struct $name<T: ::validators::ValidatedWrapper> {
+            v: ::std::collections::HashSet<T>
+        }
+
+        validated_customized_ranged_length_hash_set_struct!($name, v, $min, $max);
+    };
+    ( pub $name:ident, $equal:expr ) => {
+        validated_customized_ranged_length_hash_set!(pub $name, $equal, $equal);
+    };
+}
+
+#[macro_export]
+macro_rules! validated_customized_btree_set_struct {
+    ( $name:ident, $field:ident, input $from_set_input:ident $from_set:block ) => {
+        impl<T: ::validators::ValidatedWrapper> Clone for $name<T> where T: Clone {
+            fn clone(&self) -> Self{
+                let $field = self.$field.clone();
+
+                $name{$field}
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::std::fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Debug::fmt(&self.$field, f)
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::std::cmp::PartialEq for $name<T> where T: Ord {
+            fn eq(&self, other: &Self) -> bool {
+                self.$field == other.$field
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::validators::Validated for $name<T> {}
+
+        impl<T: ::validators::ValidatedWrapper> ::validators::ValidatedWrapper for $name<T> {
+            type Error = ::validators::ValidatedCustomizedVecError;
+
+            fn from_string(_from_string_input: String) -> Result<Self, Self::Error>{
+                Err(::validators::ValidatedCustomizedVecError::NotSupport)
+            }
+
+            fn from_str(_from_str_input: &str) -> Result<Self, Self::Error>{
+                Err(::validators::ValidatedCustomizedVecError::NotSupport)
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> ::validators::ValidatedSetWrapper<T> for $name<T> {
+            fn from_btree_set($from_set_input: ::std::collections::BTreeSet<T>) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                $name::from_btree_set($from_set_input)
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper> $name<T> {
+            pub fn as_btree_set(&self) -> &::std::collections::BTreeSet<T> {
+                &self.$field
+            }
+
+            pub fn into_btree_set(self) -> ::std::collections::BTreeSet<T> {
+                self.$field
+            }
+
+            pub fn from_btree_set($from_set_input: ::std::collections::BTreeSet<T>) -> Result<Self, ::validators::ValidatedCustomizedVecError>{
+                let $field = match $from_set {
+                    Ok(s)=> s,
+                    Err(e)=> return Err(e)
+                };
+
+                Ok($name{$field})
+            }
+        }
+
+        validated_customized_btree_set_struct_implement_se_de!($name);
+    }
+}
+
+#[cfg(feature = "serdely")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validated_customized_btree_set_struct_implement_se_de {
+     ( $name:ident ) => {
+        impl<'de, T: ::validators::ValidatedWrapper + Ord + ::validators::serde::Deserialize<'de>> ::validators::serde::Deserialize<'de> for $name<T> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: ::validators::serde::Deserializer<'de> {
+                deserializer.deserialize_seq(::validators::BTreeSetVisitor(Vec::<$name<T>>::new(), Vec::<T>::new()))
+            }
+        }
+
+        impl<T: ::validators::ValidatedWrapper + ::validators::serde::Serialize> ::validators::serde::Serialize for $name<T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: ::validators::serde::Serializer {
+                serializer.collect_seq(self.as_btree_set().iter())
+            }
+        }
+     }
+}
+
+#[cfg(not(feature = "serdely"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validated_customized_btree_set_struct_implement_se_de {
+    ( $name:ident ) => {
+
+    }
+}
+
+#[macro_export]
+macro_rules! validated_customized_ranged_length_btree_set_struct {
+    ( $name:ident, $field:expr, $min:expr, $max:expr ) => {
+        validated_customized_btree_set_struct!($name, v, input s {
+            let len = s.len();
+
+            if len > $max {
+                Err(::validators::ValidatedCustomizedVecError::Overflow)
+            } else if len < $min {
+                Err(::validators::ValidatedCustomizedVecError::Underflow)
+            } else {
+                Ok(s)
+            }
+        });
+    };
+}
+
+#[macro_export]
+macro_rules! validated_customized_ranged_length_btree_set {
+    ( $name:ident, $min:expr, $max:expr ) => {
+        struct $name<T: ::validators::ValidatedWrapper> {
+            v: ::std::collections::BTreeSet<T>
+        }
+
+        validated_customized_ranged_length_btree_set_struct!($name, v, $min, $max);
+    };
+    ( $name:ident, $equal:expr ) => {
+        validated_customized_ranged_length_btree_set!($name, $equal, $equal);
+    };
+    ( pub $name:ident, $min:expr, $max:expr ) => {
+        pub struct $name<T: ::validators::ValidatedWrapper> {
+            v: ::std::collections::BTreeSet<T>
+        }
+
+        validated_customized_ranged_length_btree_set_struct!($name, v, $min, $max);
+    };
+    ( pub $name:ident, $equal:expr ) => {
+        validated_customized_ranged_length_btree_set!(pub $name, $equal, $equal);
+    };
+}
+
+// TODO -----ValidatedCustomizedRangedLengthSet End-----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "rocketly")]
+    #[test]
+    fn test_capped() {
+        let capped = Capped::new("hello".to_string(), true);
+
+        assert_eq!("hello", capped.value());
+        assert_eq!("hello", &*capped);
+        assert!(capped.is_complete());
+        assert_eq!("hello".to_string(), capped.into_value());
+
+        let capped = Capped::new("hello".to_string(), false);
+
+        assert!(!capped.is_complete());
+    }
+
+    #[cfg(feature = "rocket_05")]
+    #[test]
+    fn test_rocket_05_number_error_conversion() {
+        let err = ValidatedCustomizedNumberError::OutRange;
+
+        let form_err: rocket_05::form::Error = err.clone().into();
+
+        assert_eq!(rocket_05::form::Error::validation(err.to_string()), form_err);
+    }
+
+    validated_customized_regex_string!(TestFromIterValidatedName, "^[A-Z][a-zA-Z]*( [A-Z][a-zA-Z]*)*$");
+    validated_customized_ranged_length_vec!(TestFromIterValidatedNames, 1, 5);
+
+    #[test]
+    fn test_from_iter_validated_ok() {
+        let names = TestFromIterValidatedNames::from_iter_validated(vec![
+            "Ron".to_string(),
+            "Magic Len".to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(2, names.as_vec().len());
+    }
+
+    #[test]
+    fn test_from_iter_validated_collects_every_error() {
+        let err = TestFromIterValidatedNames::from_iter_validated(vec![
+            "ron".to_string(),
+            "Magic Len".to_string(),
+            "harry potter".to_string(),
+        ])
+        .unwrap_err();
+
+        match err {
+            ValidatedCustomizedVecError::Multiple(errors) => {
+                assert_eq!(vec![0, 2], errors.iter().map(|(i, _)| *i).collect::<Vec<usize>>());
+            }
+            _ => panic!("expected ValidatedCustomizedVecError::Multiple"),
+        }
+    }
+
+    validated_customized_vec_with_context!(TestNamesWithMaxLen, usize, max_len, s {
+        Err(ValidatedCustomizedVecError::NotSupport)
+    }, s {
+        Err(ValidatedCustomizedVecError::NotSupport)
+    }, v {
+        if v.len() > *max_len {
+            Err(ValidatedCustomizedVecError::Overflow)
+        } else {
+            Ok(v)
+        }
+    });
+
+    #[test]
+    fn test_vec_with_context() {
+        let names = TestNamesWithMaxLen::<TestFromIterValidatedName>::from_vec_with(
+            vec![
+                TestFromIterValidatedName::from_str("Ron").unwrap(),
+                TestFromIterValidatedName::from_str("Magic Len").unwrap(),
+            ],
+            &2,
+        )
+        .unwrap();
+
+        assert_eq!(2, names.as_vec().len());
+
+        TestNamesWithMaxLen::<TestFromIterValidatedName>::from_vec_with(
+            vec![
+                TestFromIterValidatedName::from_str("Ron").unwrap(),
+                TestFromIterValidatedName::from_str("Magic Len").unwrap(),
+            ],
+            &1,
+        )
+        .unwrap_err();
+    }
+
+    validated_customized_regex_number!(TestFilteredScore, u8, "^[0-9]{1,3}$");
+    validated_customized_vec!(TestFilteredScores, filter n {
+        // Clamp every element down to 100 instead of just validating it. The filter sees each
+        // element as the generic `T`, so it goes through the element's string form.
+        ValidatedWrapper::from_string(n.to_string().parse::<u8>().unwrap().min(100).to_string()).unwrap()
+    }, from_string s {
+        Err(ValidatedCustomizedVecError::NotSupport)
+    }, from_str s {
+        Err(ValidatedCustomizedVecError::NotSupport)
+    }, from_vec v {
+        Ok(v)
+    });
+
+    #[test]
+    fn test_vec_filter_normalizes_elements() {
+        let scores = TestFilteredScores::from_vec(vec![
+            TestFilteredScore::from_str("50").unwrap(),
+            TestFilteredScore::from_str("150").unwrap(),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            vec![50, 100],
+            scores.as_vec().iter().map(|s| s.get_number()).collect::<Vec<u8>>()
+        );
+    }
+
+    #[cfg(feature = "rocketly")]
+    validated_customized_ranged_length_vec!(TestRepeatedFormNames, 1, 5);
+
+    #[cfg(feature = "rocketly")]
+    #[test]
+    fn test_vec_from_form_collects_repeated_fields() {
+        use rocket::request::{FormItems, FromForm};
+
+        let mut items = FormItems::from("tags=Ron&tags=Magic+Len");
+
+        let names = TestRepeatedFormNames::<TestFromIterValidatedName>::from_form(&mut items, false).unwrap();
+
+        assert_eq!(
+            vec!["Ron".to_string(), "Magic Len".to_string()],
+            names.as_vec().iter().map(|n| n.to_string()).collect::<Vec<String>>()
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! The procedural side of the `validators` crate.
+//!
+//! `#[derive(Validator)]` reads a single `#[validator(...)]` attribute off the annotated struct,
+//! uses the attribute's leading identifier to pick a `validator_handlers` module, and asks that
+//! module to expand the rest of the attribute into the struct's validator implementation. This
+//! replaces the declarative `validated_customized_*!` macros for validators written against the
+//! new trait-based API: each kind (`text`, `boolean`, `base64_decoded`, `base64_url_decoded`,
+//! `base32_decoded`) is gated behind a crate feature of the same name, so unused handlers don't
+//! cost compile time. Any other kind, `number` included, is rejected with a compile error.
+
+extern crate proc_macro;
+
+mod validator_handlers;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, Meta};
+
+#[proc_macro_derive(Validator, attributes(validator))]
+pub fn derive_validator(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let expanded = find_validator_meta(&ast).and_then(|meta| expand_meta(&ast, &meta));
+
+    match expanded {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Finds the struct's single `#[validator(...)]` attribute and parses its contents as a `Meta`.
+fn find_validator_meta(ast: &DeriveInput) -> syn::Result<Meta> {
+    let mut found = None;
+
+    for attr in &ast.attrs {
+        if attr.path().is_ident("validator") {
+            if found.is_some() {
+                return Err(syn::Error::new_spanned(attr, "only one `#[validator(...)]` attribute is allowed"));
+            }
+
+            found = Some(attr.parse_args::<Meta>()?);
+        }
+    }
+
+    found.ok_or_else(|| {
+        syn::Error::new_spanned(&ast.ident, "expected a `#[validator(...)]` attribute, e.g. `#[validator(text(regex = \"...\"))]`")
+    })
+}
+
+/// Dispatches to the `validator_handlers` module named by `meta`'s leading identifier.
+fn expand_meta(ast: &DeriveInput, meta: &Meta) -> syn::Result<proc_macro2::TokenStream> {
+    let kind = meta
+        .path()
+        .get_ident()
+        .ok_or_else(|| syn::Error::new_spanned(meta, "expected a validator kind such as `text` or `boolean`"))?
+        .to_string();
+
+    match kind.as_str() {
+        #[cfg(feature = "text")]
+        "text" => validator_handlers::text::expand(ast, meta),
+        #[cfg(feature = "boolean")]
+        "boolean" => validator_handlers::boolean::expand(ast, meta),
+        #[cfg(feature = "base64_decoded")]
+        "base64_decoded" => validator_handlers::base64_decoded::expand(ast, meta),
+        #[cfg(feature = "base64_url_decoded")]
+        "base64_url_decoded" => validator_handlers::base64_url_decoded::expand(ast, meta),
+        #[cfg(feature = "base32_decoded")]
+        "base32_decoded" => validator_handlers::base32_decoded::expand(ast, meta),
+        _ => Err(syn::Error::new_spanned(meta, format!("unsupported or disabled validator kind `{}`", kind))),
+    }
+}
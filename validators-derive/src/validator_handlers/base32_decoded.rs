@@ -0,0 +1,34 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Meta};
+
+use super::decoded_field::expect_single_unnamed_field;
+
+/// Expands `#[validator(base32_decoded)]` on a `struct Name(Vec<u8>);` into a `ValidateBytes`
+/// impl that decodes RFC 4648 base32 into the wrapped bytes.
+pub fn expand(ast: &DeriveInput, meta: &Meta) -> syn::Result<TokenStream> {
+    match meta {
+        Meta::Path(_) => (),
+        _ => return Err(syn::Error::new_spanned(meta, "expected a bare `base32_decoded`")),
+    }
+
+    let ident = expect_single_unnamed_field(ast)?;
+
+    Ok(quote! {
+        impl ::validators::ValidateBytes for #ident {
+            type Error = ::validators::ValidateBytesError;
+
+            fn parse_string(s: String) -> Result<Self, Self::Error> {
+                Self::parse_str(&s)
+            }
+
+            fn parse_str(s: &str) -> Result<Self, Self::Error> {
+                Ok(#ident(::validators::bytes::decode_base32(s)?))
+            }
+
+            fn validate_str(s: &str) -> Result<(), Self::Error> {
+                ::validators::bytes::decode_base32(s).map(|_| ())
+            }
+        }
+    })
+}
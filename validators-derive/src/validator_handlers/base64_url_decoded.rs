@@ -0,0 +1,34 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Meta};
+
+use super::decoded_field::expect_single_unnamed_field;
+
+/// Expands `#[validator(base64_url_decoded)]` on a `struct Name(Vec<u8>);` into a `ValidateBytes`
+/// impl that decodes the URL- and filename-safe base64 alphabet into the wrapped bytes.
+pub fn expand(ast: &DeriveInput, meta: &Meta) -> syn::Result<TokenStream> {
+    match meta {
+        Meta::Path(_) => (),
+        _ => return Err(syn::Error::new_spanned(meta, "expected a bare `base64_url_decoded`")),
+    }
+
+    let ident = expect_single_unnamed_field(ast)?;
+
+    Ok(quote! {
+        impl ::validators::ValidateBytes for #ident {
+            type Error = ::validators::ValidateBytesError;
+
+            fn parse_string(s: String) -> Result<Self, Self::Error> {
+                Self::parse_str(&s)
+            }
+
+            fn parse_str(s: &str) -> Result<Self, Self::Error> {
+                Ok(#ident(::validators::bytes::decode_base64(s, true)?))
+            }
+
+            fn validate_str(s: &str) -> Result<(), Self::Error> {
+                ::validators::bytes::decode_base64(s, true).map(|_| ())
+            }
+        }
+    })
+}
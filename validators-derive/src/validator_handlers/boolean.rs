@@ -0,0 +1,38 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, Meta};
+
+/// Expands `#[validator(boolean)]` into a `ValidateString` impl for a unit struct that accepts
+/// `"true"`/`"false"` (case-insensitively).
+pub fn expand(ast: &DeriveInput, meta: &Meta) -> syn::Result<TokenStream> {
+    match meta {
+        Meta::Path(_) => (),
+        _ => return Err(Error::new_spanned(meta, "expected a bare `boolean`, e.g. `#[validator(boolean)]`")),
+    }
+
+    let ident = &ast.ident;
+
+    Ok(quote! {
+        impl ::validators::ValidateString for #ident {
+            type Error = ::validators::ValidateStringError;
+
+            fn parse_string(s: String) -> Result<Self, Self::Error> {
+                Self::parse_str(&s)
+            }
+
+            fn parse_str(s: &str) -> Result<Self, Self::Error> {
+                Self::validate_str(s)?;
+
+                Ok(#ident)
+            }
+
+            fn validate_str(s: &str) -> Result<(), Self::Error> {
+                if s.eq_ignore_ascii_case("true") || s.eq_ignore_ascii_case("false") {
+                    Ok(())
+                } else {
+                    Err(::validators::ValidateStringError::NotMatch)
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,15 @@
+use syn::{DeriveInput, Error, Fields};
+
+/// Validates that `ast` is a tuple struct with a single unnamed field (the `Vec<u8>` the decoded
+/// bytes are stored in) and returns its identifier, e.g. `struct MyBytes(Vec<u8>);`.
+pub fn expect_single_unnamed_field(ast: &DeriveInput) -> syn::Result<&syn::Ident> {
+    let data = match &ast.data {
+        syn::Data::Struct(data) => data,
+        _ => return Err(Error::new_spanned(&ast.ident, "expected a tuple struct")),
+    };
+
+    match &data.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(&ast.ident),
+        _ => Err(Error::new_spanned(&ast.ident, "expected a tuple struct with a single `Vec<u8>` field, e.g. `struct MyBytes(Vec<u8>);`")),
+    }
+}
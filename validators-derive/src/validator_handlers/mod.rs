@@ -1,3 +1,10 @@
+#[cfg(any(
+    feature = "base32_decoded",
+    feature = "base64_decoded",
+    feature = "base64_url_decoded"
+))]
+mod decoded_field;
+
 #[cfg(feature = "base32")]
 pub mod base32;
 #[cfg(feature = "base32_decoded")]
@@ -0,0 +1,73 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{punctuated::Punctuated, DeriveInput, Error, Expr, ExprLit, Lit, Meta, Token};
+
+/// Expands `#[validator(text(regex = "..."))]` into a `ValidateString` impl for a unit struct
+/// that accepts a string iff it matches the given regular expression.
+pub fn expand(ast: &DeriveInput, meta: &Meta) -> syn::Result<TokenStream> {
+    let list = match meta {
+        Meta::List(list) => list,
+        _ => return Err(Error::new_spanned(meta, "expected `text(regex = \"...\")`")),
+    };
+
+    let params = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+    let mut regex = None;
+
+    for param in &params {
+        let name_value = match param {
+            Meta::NameValue(name_value) => name_value,
+            _ => return Err(Error::new_spanned(param, "expected `name = \"value\"`")),
+        };
+
+        if name_value.path.is_ident("regex") {
+            regex = Some(expect_str_literal(&name_value.value)?);
+        } else {
+            return Err(Error::new_spanned(&name_value.path, "unknown `text` validator parameter"));
+        }
+    }
+
+    let regex = regex.ok_or_else(|| Error::new_spanned(list, "`text` validator requires a `regex` parameter"))?;
+
+    let ident = &ast.ident;
+
+    Ok(quote! {
+        impl ::validators::ValidateString for #ident {
+            type Error = ::validators::ValidateStringError;
+
+            fn parse_string(s: String) -> Result<Self, Self::Error> {
+                Self::validate_str(&s)?;
+
+                Ok(#ident)
+            }
+
+            fn parse_str(s: &str) -> Result<Self, Self::Error> {
+                Self::validate_str(s)?;
+
+                Ok(#ident)
+            }
+
+            fn validate_str(s: &str) -> Result<(), Self::Error> {
+                ::validators::lazy_static! {
+                    static ref RE: ::validators::regex::Regex = ::validators::regex::Regex::new(#regex).unwrap();
+                }
+
+                if RE.is_match(s) {
+                    Ok(())
+                } else {
+                    Err(::validators::ValidateStringError::NotMatch)
+                }
+            }
+        }
+    })
+}
+
+fn expect_str_literal(expr: &Expr) -> syn::Result<String> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit_str),
+            ..
+        }) => Ok(lit_str.value()),
+        _ => Err(Error::new_spanned(expr, "expected a string literal")),
+    }
+}
@@ -0,0 +1,102 @@
+//! Decoding helpers shared by the `base64_decoded`, `base64_url_decoded`, and `base32_decoded`
+//! validator kinds in `validators-derive` so each derived struct's generated code stays a thin
+//! wrapper around a single call here.
+
+use crate::traits::ValidateBytesError;
+
+#[inline]
+fn base64_char_value(c: u8, url_safe: bool) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' if !url_safe => Some(62),
+        b'/' if !url_safe => Some(63),
+        b'-' if url_safe => Some(62),
+        b'_' if url_safe => Some(63),
+        _ => None,
+    }
+}
+
+/// Decodes a base64 (`url_safe = false`) or base64url (`url_safe = true`) string into bytes.
+/// `=` padding may be present or omitted.
+pub fn decode_base64(s: &str, url_safe: bool) -> Result<Vec<u8>, ValidateBytesError> {
+    let bytes = s.as_bytes();
+
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let unpadded_len = bytes.iter().take_while(|&&c| c != b'=').count();
+
+    if unpadded_len % 4 == 1 || bytes[unpadded_len..].iter().any(|&c| c != b'=') {
+        return Err(ValidateBytesError::IncorrectFormat);
+    }
+
+    let mut output = Vec::with_capacity(unpadded_len / 4 * 3 + 3);
+
+    for chunk in bytes[..unpadded_len].chunks(4) {
+        let mut values = [0u8; 4];
+
+        for (i, &c) in chunk.iter().enumerate() {
+            values[i] = base64_char_value(c, url_safe).ok_or(ValidateBytesError::IncorrectFormat)?;
+        }
+
+        let n = (u32::from(values[0]) << 18)
+            | (u32::from(values[1]) << 12)
+            | (u32::from(values[2]) << 6)
+            | u32::from(values[3]);
+
+        output.push((n >> 16) as u8);
+
+        if chunk.len() > 2 {
+            output.push((n >> 8) as u8);
+        }
+
+        if chunk.len() > 3 {
+            output.push(n as u8);
+        }
+    }
+
+    Ok(output)
+}
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+#[inline]
+fn base32_char_value(c: u8) -> Option<u8> {
+    BASE32_ALPHABET.iter().position(|&a| a == c.to_ascii_uppercase()).map(|i| i as u8)
+}
+
+/// Decodes an RFC 4648 base32 string into bytes. `=` padding may be present or omitted.
+pub fn decode_base32(s: &str) -> Result<Vec<u8>, ValidateBytesError> {
+    let bytes = s.as_bytes();
+
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let unpadded_len = bytes.iter().take_while(|&&c| c != b'=').count();
+
+    if bytes[unpadded_len..].iter().any(|&c| c != b'=') {
+        return Err(ValidateBytesError::IncorrectFormat);
+    }
+
+    let mut output = Vec::with_capacity(unpadded_len * 5 / 8 + 1);
+    let mut buffer = 0u64;
+    let mut bits = 0u32;
+
+    for &c in &bytes[..unpadded_len] {
+        let value = base32_char_value(c).ok_or(ValidateBytesError::IncorrectFormat)?;
+
+        buffer = (buffer << 5) | u64::from(value);
+        bits += 5;
+
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+        }
+    }
+
+    Ok(output)
+}
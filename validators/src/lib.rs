@@ -0,0 +1,16 @@
+//! Traits shared by validators generated through `#[derive(Validator)]` (see the
+//! `validators-derive` crate) as well as by the hand-written validators in the main `validators`
+//! crate.
+
+pub extern crate regex;
+
+#[macro_use]
+pub extern crate lazy_static;
+
+pub mod bytes;
+pub mod traits;
+
+pub use traits::{
+    ValidateBytes, ValidateBytesError, ValidateString, ValidateStringError,
+    ValidateUnsignedInteger,
+};
@@ -0,0 +1,7 @@
+mod validate_bytes;
+mod validate_string;
+mod validate_unsigned_integer;
+
+pub use validate_bytes::{ValidateBytes, ValidateBytesError};
+pub use validate_string::{ValidateString, ValidateStringError};
+pub use validate_unsigned_integer::ValidateUnsignedInteger;
@@ -0,0 +1,27 @@
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// The errors that a `ValidateBytes` implementor's `parse_str`/`parse_string` can return.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ValidateBytesError {
+    IncorrectFormat,
+}
+
+impl Display for ValidateBytesError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for ValidateBytesError {}
+
+/// Validate a string and decode it into raw bytes, such as a base64 or base32 payload.
+pub trait ValidateBytes: Sized {
+    type Error;
+
+    fn parse_string(s: String) -> Result<Self, Self::Error>;
+
+    fn parse_str(s: &str) -> Result<Self, Self::Error>;
+
+    fn validate_str(s: &str) -> Result<(), Self::Error>;
+}
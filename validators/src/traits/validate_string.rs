@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// The errors that a `ValidateString` implementor's `parse_str`/`parse_string` can return.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ValidateStringError {
+    NotMatch,
+    TooShort,
+    TooLong,
+}
+
+impl Display for ValidateStringError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl Error for ValidateStringError {}
+
+/// Validate and deserialize strings.
+pub trait ValidateString: Sized {
+    type Error;
+
+    fn parse_string(s: String) -> Result<Self, Self::Error>;
+
+    fn parse_str(s: &str) -> Result<Self, Self::Error>;
+
+    fn validate_str(s: &str) -> Result<(), Self::Error>;
+}